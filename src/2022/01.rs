@@ -0,0 +1,76 @@
+use aoc::Solution;
+
+pub fn sum_top_n_calorie_counts(input: &str, num: usize) -> u32 {
+    let mut calorie_counts: Vec<u32> = Vec::new();
+    let mut calorie_count = 0;
+
+    for line in input.lines() {
+        if let Ok(calories) = line.parse::<u32>() {
+            calorie_count += calories;
+        } else {
+            calorie_counts.push(calorie_count);
+            calorie_count = 0;
+        }
+    }
+
+    // We skip the empty line at the end of the file, so add the remaining calories.
+    calorie_counts.push(calorie_count);
+
+    assert!(num < calorie_counts.len());
+
+    calorie_counts.sort();
+
+    calorie_count = 0;
+
+    let mut iter = calorie_counts.iter().rev();
+    let mut countdown = num;
+    while countdown > 0 {
+        calorie_count += *iter.next().unwrap();
+        countdown -= 1;
+    }
+
+    calorie_count
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Parsed = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> String {
+        String::from(input)
+    }
+
+    fn part_one(input: &String) -> Option<u32> {
+        Some(sum_top_n_calorie_counts(input, 1))
+    }
+
+    fn part_two(input: &String) -> Option<u32> {
+        Some(sum_top_n_calorie_counts(input, 3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day1 {
+        use super::super::*;
+
+        #[test]
+        fn test_part_one() {
+            let input = aoc::read_file(2022, "examples", 1).unwrap();
+            assert_eq!(Day01::part_one(&input), Some(24000));
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = aoc::read_file(2022, "examples", 1).unwrap();
+            assert_eq!(Day01::part_two(&input), Some(45000));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use aoc::Solution;
+use std::collections::BTreeSet;
+
+pub fn get_priority(c: char) -> u32 {
+    match c {
+        'a'..='z' => c as u32 - 96, // 'a' starts at 97 in ASCII, but challenge says it starts at 1
+        'A'..='Z' => c as u32 - 64 + 26, // 'A' starts at 65 in ASCII, but challenge says it starts at 26
+        _ => panic!("unsupported priority for {}", c),
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Parsed = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> String {
+        String::from(input)
+    }
+
+    fn part_one(input: &String) -> Option<u32> {
+        let mut priority_sum: u32 = 0;
+
+        for line in input.lines() {
+            let first_compartment: BTreeSet<char> = line[..line.len() / 2].chars().collect();
+
+            for item in line[line.len() / 2..].chars() {
+                if first_compartment.contains(&item) {
+                    priority_sum += get_priority(item);
+
+                    break;
+                }
+            }
+        }
+
+        Some(priority_sum)
+    }
+
+    fn part_two(input: &String) -> Option<u32> {
+        let mut priority_sum: u32 = 0;
+        let mut groups: Vec<BTreeSet<char>> = Vec::new();
+
+        for group in input.lines() {
+            if groups.len() < 3 {
+                groups.push(group.chars().collect());
+            }
+
+            if groups.len() == 3 {
+                for item in &groups[0] {
+                    if groups[1].contains(item) && groups[2].contains(item) {
+                        priority_sum += get_priority(*item);
+
+                        break;
+                    }
+                }
+
+                groups.clear();
+            }
+        }
+
+        Some(priority_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day3 {
+        use super::super::*;
+
+        #[test]
+        fn test_part_one() {
+            let input = aoc::read_file(2022, "examples", 3).unwrap();
+            assert_eq!(Day03::part_one(&input), Some(157));
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = aoc::read_file(2022, "examples", 3).unwrap();
+            assert_eq!(Day03::part_two(&input), Some(70));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use aoc::Solution;
+
+pub fn str_to_assignment_pair(s: &str) -> [(u32, u32); 2] {
+    let mut iter = s.split(",");
+
+    let mut half = iter.next().unwrap().split("-");
+    let lower: u32 = half.next().unwrap().parse().unwrap();
+    let upper: u32 = half.next().unwrap().parse().unwrap();
+    let first = (lower, upper);
+
+    let mut half = iter.next().unwrap().split("-");
+    let lower: u32 = half.next().unwrap().parse().unwrap();
+    let upper: u32 = half.next().unwrap().parse().unwrap();
+    let second = (lower, upper);
+
+    [first, second]
+}
+
+pub fn contains(pair: [(u32, u32); 2]) -> bool {
+    (pair[0].0 >= pair[1].0 && pair[0].1 <= pair[1].1)
+        || (pair[1].0 >= pair[0].0 && pair[1].1 <= pair[0].1)
+}
+
+pub fn overlap(pair: [(u32, u32); 2]) -> bool {
+    let (lower, upper) = if pair[0].0 < pair[1].0 {
+        (pair[0], pair[1])
+    } else {
+        (pair[1], pair[0])
+    };
+
+    lower.1 >= upper.0
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Parsed = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> String {
+        String::from(input)
+    }
+
+    fn part_one(input: &String) -> Option<u32> {
+        Some(
+            input
+                .lines()
+                .map(str_to_assignment_pair)
+                .fold(0, |acc, pair| if contains(pair) { acc + 1 } else { acc }),
+        )
+    }
+
+    fn part_two(input: &String) -> Option<u32> {
+        Some(
+            input
+                .lines()
+                .map(str_to_assignment_pair)
+                .fold(0, |acc, pair| if overlap(pair) { acc + 1 } else { acc }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day4 {
+        use super::super::*;
+
+        #[test]
+        fn test_part_one() {
+            let input = aoc::read_file(2022, "examples", 4).unwrap();
+            assert_eq!(Day04::part_one(&input), Some(2));
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = aoc::read_file(2022, "examples", 4).unwrap();
+            assert_eq!(Day04::part_two(&input), Some(4));
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use aoc::Solution;
+use std::collections::BTreeSet;
+
+pub fn first_n_unique(n: u32, s: &str) -> Option<u32> {
+    for (i, w) in s.as_bytes().windows(n as usize).enumerate() {
+        let set: BTreeSet<char> = w.iter().map(|c| *c as char).collect();
+        if set.len() == n as usize {
+            return Some(i as u32 + n);
+        }
+    }
+
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Parsed = String;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> String {
+        String::from(input)
+    }
+
+    fn part_one(input: &String) -> Option<u32> {
+        first_n_unique(4, input)
+    }
+
+    fn part_two(input: &String) -> Option<u32> {
+        first_n_unique(14, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day6 {
+        use super::super::*;
+
+        #[test]
+        fn test_part_one() {
+            let input = aoc::read_file(2022, "examples", 6).unwrap();
+            assert_eq!(Day06::part_one(&input), Some(7));
+        }
+
+        #[test]
+        fn test_part_one_extra1() {
+            assert_eq!(
+                Day06::part_one(&Day06::parse("bvwbjplbgvbhsrlpgdmjqwftvncz")),
+                Some(5)
+            );
+        }
+
+        #[test]
+        fn test_part_one_extra2() {
+            assert_eq!(
+                Day06::part_one(&Day06::parse("nppdvjthqldpwncqszvftbrmjlhg")),
+                Some(6)
+            );
+        }
+
+        #[test]
+        fn test_part_one_extra3() {
+            assert_eq!(
+                Day06::part_one(&Day06::parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")),
+                Some(10)
+            );
+        }
+
+        #[test]
+        fn test_part_one_extra4() {
+            assert_eq!(
+                Day06::part_one(&Day06::parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")),
+                Some(11)
+            );
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = aoc::read_file(2022, "examples", 6).unwrap();
+            assert_eq!(Day06::part_two(&input), Some(19));
+        }
+
+        #[test]
+        fn test_part_two_extra1() {
+            assert_eq!(
+                Day06::part_two(&Day06::parse("bvwbjplbgvbhsrlpgdmjqwftvncz")),
+                Some(23)
+            );
+        }
+
+        #[test]
+        fn test_part_two_extra2() {
+            assert_eq!(
+                Day06::part_two(&Day06::parse("nppdvjthqldpwncqszvftbrmjlhg")),
+                Some(23)
+            );
+        }
+
+        #[test]
+        fn test_part_two_extra3() {
+            assert_eq!(
+                Day06::part_two(&Day06::parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")),
+                Some(29)
+            );
+        }
+
+        #[test]
+        fn test_part_two_extra4() {
+            assert_eq!(
+                Day06::part_two(&Day06::parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")),
+                Some(26)
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use aoc::Solution;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    modifier: Option<u64>,
+    divisor: u64,
+    true_monkey: usize,
+    false_monkey: usize,
+    inspect_count: u64,
+}
+
+impl Monkey {
+    pub fn build(s: &[&str]) -> Self {
+        let mut iter = s[1].split(": ");
+        iter.next();
+        let items: Vec<u64> = iter
+            .next()
+            .unwrap()
+            .split(", ")
+            .map(|n| n.parse::<u64>().unwrap())
+            .collect();
+
+        let operation = if s[2].contains("*") {
+            Operation::Multiply
+        } else {
+            Operation::Add
+        };
+
+        let modifier = s[2]
+            .split_whitespace()
+            .next_back()
+            .unwrap()
+            .parse::<u64>()
+            .ok();
+
+        let divisor = s[3]
+            .split_whitespace()
+            .next_back()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+
+        let true_monkey = s[4]
+            .split_whitespace()
+            .next_back()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+
+        let false_monkey = s[5]
+            .split_whitespace()
+            .next_back()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+
+        Self {
+            items,
+            operation,
+            modifier,
+            divisor,
+            true_monkey,
+            false_monkey,
+            inspect_count: 0,
+        }
+    }
+
+    fn apply_worry(&self, item: u64) -> u64 {
+        let modifier = self.modifier.unwrap_or(item);
+        match self.operation {
+            Operation::Add => item + modifier,
+            Operation::Multiply => item * modifier,
+        }
+    }
+
+    // Without boredom worry levels grow without bound, so keep them modulo the
+    // product of all divisors. This preserves every monkey's divisibility test.
+    fn apply_bored(&self, item: u64, modulus: u64, should_apply_bored: bool) -> u64 {
+        if should_apply_bored {
+            item / 3
+        } else {
+            item % modulus
+        }
+    }
+
+    #[allow(clippy::manual_is_multiple_of)]
+    fn get_monkey_receiver(&self, item: u64) -> usize {
+        if item % self.divisor == 0 {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        }
+    }
+
+    fn receive_items(&mut self, items: &mut Vec<u64>) {
+        self.items.append(items);
+    }
+
+    pub fn remove_first(&mut self) -> u64 {
+        self.items.remove(0)
+    }
+
+    pub fn inspect_item(&mut self, modulus: u64, should_apply_bored: bool) -> (u64, usize) {
+        assert!(!self.is_done());
+
+        self.inspect_count += 1;
+
+        let mut item = self.remove_first();
+        item = self.apply_worry(item);
+        item = self.apply_bored(item, modulus, should_apply_bored);
+
+        (item, self.get_monkey_receiver(item))
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get_inspect_count(&self) -> u64 {
+        self.inspect_count
+    }
+
+    pub fn get_divisor(&self) -> u64 {
+        self.divisor
+    }
+}
+
+pub fn build_monkeys(s: &str) -> Vec<Monkey> {
+    let monkey_lines: Vec<&str> = s.lines().collect();
+    monkey_lines[..].chunks(7).map(Monkey::build).collect()
+}
+
+pub fn get_monkey_business(monkeys: &[Monkey], n: u32, apply_bored: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let modulus: u64 = monkeys.iter().map(Monkey::get_divisor).product();
+
+    for _ in 0..n {
+        for index in 0..monkeys.len() {
+            let monkey = &mut monkeys[index];
+            let mut sent_items: HashMap<usize, Vec<u64>> = HashMap::new();
+
+            while !monkey.is_done() {
+                let (item, receiver) = monkey.inspect_item(modulus, apply_bored);
+                sent_items.entry(receiver).or_default().push(item);
+            }
+
+            for (index, mut items) in sent_items {
+                monkeys[index].receive_items(&mut items);
+            }
+        }
+    }
+
+    monkeys.sort_by(|a, b| a.get_inspect_count().cmp(&b.get_inspect_count()).reverse());
+    monkeys[0].get_inspect_count() * monkeys[1].get_inspect_count()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Parsed = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Vec<Monkey> {
+        build_monkeys(input)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>) -> Option<u64> {
+        Some(get_monkey_business(monkeys, 20, true))
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>) -> Option<u64> {
+        Some(get_monkey_business(monkeys, 10_000, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day11 {
+        use super::super::*;
+
+        #[test]
+        fn test_part_one() {
+            let input = aoc::read_file(2022, "examples", 11).unwrap();
+            assert_eq!(Day11::part_one(&Day11::parse(&input)), Some(10605));
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = aoc::read_file(2022, "examples", 11).unwrap();
+            assert_eq!(Day11::part_two(&Day11::parse(&input)), Some(2_713_310_158));
+        }
+    }
+}
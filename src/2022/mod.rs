@@ -0,0 +1,24 @@
+//! Solutions of 2022. `cargo scaffold` adds a module for every new day and registers it in `../days.rs`.
+
+#[path = "01.rs"]
+pub mod day01;
+#[path = "02.rs"]
+pub mod day02;
+#[path = "03.rs"]
+pub mod day03;
+#[path = "04.rs"]
+pub mod day04;
+#[path = "05.rs"]
+pub mod day05;
+#[path = "06.rs"]
+pub mod day06;
+#[path = "07.rs"]
+pub mod day07;
+#[path = "08.rs"]
+pub mod day08;
+#[path = "09.rs"]
+pub mod day09;
+#[path = "10.rs"]
+pub mod day10;
+#[path = "11.rs"]
+pub mod day11;
@@ -0,0 +1,198 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Known answers for real inputs, read from `src/YYYY/answers.toml`:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 24000
+//! part2 = "45000"
+//! ```
+
+use crate::runner::{DayResult, Status};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::{fs, io};
+
+pub fn answers_path(year: u16) -> PathBuf {
+    crate::year_dir(year).join("answers.toml")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// Loads the answers of `year`. A missing file means that no answers are known yet.
+    pub fn load(year: u16) -> Result<Self, String> {
+        match fs::read_to_string(answers_path(year)) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        let mut day: Option<u8> = None;
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let error = |message: &str| format!("line {}: {}", index + 1, message);
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = match section.trim().strip_prefix("day").map(str::parse) {
+                    Some(Ok(day)) => Some(day),
+                    _ => return Err(error("expected a section like [day01]")),
+                };
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `part1 = <answer>`"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(error("expected key part1 or part2")),
+            };
+            let day = day.ok_or_else(|| error("answer outside of a [dayNN] section"))?;
+
+            answers.insert(
+                (day, part),
+                parse_value(value.trim()).map_err(|e| error(&e))?,
+            );
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn verify(&self, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if answer == Some(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: String::from(expected),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Sets the verdict of every part of `result` that ran to completion.
+    pub fn check(&self, result: &mut DayResult) {
+        for part in result.parts.iter_mut() {
+            if part.status == Status::Timeout {
+                continue;
+            }
+            part.verdict = Some(self.verify(result.day, part.part, part.answer.as_deref()));
+        }
+    }
+}
+
+/// Parses a basic TOML string or an integer.
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(rest) = value.strip_prefix('"') {
+        let mut s = String::new();
+        let mut chars = rest.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => {
+                    let trailing = chars.as_str().trim();
+                    if trailing.is_empty() || trailing.starts_with('#') {
+                        return Ok(s);
+                    }
+                    return Err(format!("unexpected \"{}\" after string", trailing));
+                }
+                '\\' => match chars.next() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some(c @ ('"' | '\\')) => s.push(c),
+                    _ => return Err(String::from("unsupported escape sequence")),
+                },
+                c => s.push(c),
+            }
+        }
+        Err(String::from("unterminated string"))
+    } else {
+        // drop trailing comments.
+        let value = value.split('#').next().unwrap_or_default().trim();
+        match value.replace('_', "").parse::<i64>() {
+            Ok(n) => Ok(n.to_string()),
+            Err(_) => Err(format!("expected a string or integer, found \"{}\"", value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# comment\n\
+             [day01]\n\
+             part1 = 24_000 # inline comment\n\
+             part2 = \"45000\"\n\
+             \n\
+             [day05]\n\
+             part1 = \"C\\\"MZ\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("24000"));
+        assert_eq!(answers.get(1, 2), Some("45000"));
+        assert_eq!(answers.get(5, 1), Some("C\"MZ"));
+        assert_eq!(answers.get(5, 2), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part1 = 1").is_err());
+        assert!(Answers::parse("[first]").is_err());
+        assert!(Answers::parse("[day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = \"open").is_err());
+        assert!(Answers::parse("[day01]\npart1 = abc").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("[day02]\npart1 = 15").unwrap();
+
+        assert_eq!(answers.verify(2, 1, Some("15")), Verdict::Pass);
+        assert_eq!(
+            answers.verify(2, 1, Some("16")),
+            Verdict::Fail {
+                expected: String::from("15")
+            }
+        );
+        assert_eq!(answers.verify(2, 2, Some("12")), Verdict::Unknown);
+    }
+}
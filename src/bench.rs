@@ -0,0 +1,177 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{Day, Parsed, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Exact number of timed runs. When unset, runs are repeated until `budget` is spent.
+    pub iterations: Option<u32>,
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            iterations: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_nanos() as f64 - mean.as_nanos() as f64).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            iterations: n as u32,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub stats: Stats,
+}
+
+/// Runs `step` until `options` are satisfied and returns its last output with the timings.
+fn sample<T>(mut step: impl FnMut() -> T, options: &BenchOptions) -> (T, Stats) {
+    for _ in 0..options.warmup {
+        step();
+    }
+
+    let mut samples: Vec<Duration> = Vec::new();
+    let started = Instant::now();
+
+    let output = loop {
+        let timer = Instant::now();
+        let output = step();
+        samples.push(timer.elapsed());
+
+        let done = match options.iterations {
+            Some(iterations) => samples.len() as u32 >= iterations,
+            None => started.elapsed() >= options.budget,
+        };
+
+        if done {
+            break output;
+        }
+    };
+
+    (output, Stats::from_samples(&mut samples))
+}
+
+/// Benchmarks the parse step of `day`. Returns the parsed input along with the timings.
+pub fn bench_parse(day: &Day, input: &str, options: &BenchOptions) -> (Parsed, Stats) {
+    sample(|| (day.parse)(input), options)
+}
+
+pub fn bench_part(day: &Day, part: u8, parsed: &dyn Any, options: &BenchOptions) -> BenchResult {
+    let solver = day.part(part);
+    let (answer, stats) = sample(|| solver(parsed, &mut |solve| solve()), options);
+
+    BenchResult {
+        part,
+        answer,
+        stats,
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{}(min: {:.2?}, median: {:.2?}, mean: {:.2?} ± {:.2?}, {} iterations){}",
+        ANSI_ITALIC,
+        stats.min,
+        stats.median,
+        stats.mean,
+        stats.stddev,
+        stats.iterations,
+        ANSI_RESET
+    )
+}
+
+pub fn print_bench_parse(stats: &Stats) {
+    println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+    println!("{}", format_stats(stats));
+}
+
+pub fn print_bench(result: &BenchResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    println!(
+        "{} {}",
+        result.answer.as_deref().unwrap_or("not solved."),
+        format_stats(&result.stats)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [4, 2, 6, 8]
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect();
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+    }
+
+    #[test]
+    fn test_bench_part_iterations() {
+        let day = crate::days::get(2022, 1).unwrap();
+        let options = BenchOptions {
+            warmup: 1,
+            iterations: Some(5),
+            budget: Duration::ZERO,
+        };
+
+        let input = crate::read_file(2022, "examples", 1).unwrap();
+        let (parsed, stats) = bench_parse(&day, &input, &options);
+        assert_eq!(stats.iterations, 5);
+
+        let result = bench_part(&day, 1, &*parsed, &options);
+        assert_eq!(result.stats.iterations, 5);
+        assert_eq!(result.answer, Some(String::from("24000")));
+    }
+}
@@ -2,104 +2,115 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
+use aoc::cache::{self, Entry, Integrity, Manifest};
+use aoc::client::Client;
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: u16,
+    /// Download the input again even if it exists.
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year: args
+            .opt_value_from_fn(["-y", "--year"], aoc::parse_year)?
+            .unwrap_or_else(aoc::default_year),
+        force: args.contains("--force"),
+        day: args.free_from_fn(aoc::parse_day)?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
-
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
-
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
-        "--file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    let input_path = aoc::input_path(args.year, "inputs", args.day);
+    let manifest_path = cache::manifest_path(args.year);
+    let mut manifest = match Manifest::load(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!(
+                "Failed to read \"{}\", it will be rewritten: {}",
+                manifest_path.display(),
+                e
+            );
+            Manifest::default()
+        }
+    };
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
+    if !args.force {
+        if let Ok(input) = aoc::read_file(args.year, "inputs", args.day) {
+            match manifest.verify(args.day, &input) {
+                Integrity::Modified => println!(
+                    "🎄 \"{}\" was changed since it was downloaded. Pass --force to replace it.",
+                    input_path.display()
+                ),
+                _ => println!(
+                    "🎄 \"{}\" is already downloaded. Pass --force to download it again.",
+                    input_path.display()
+                ),
             }
+            return;
         }
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("{}", e);
+            process::exit(1);
         }
-    }
+    };
+
+    println!("Downloading input for day {}, {}...", args.day, args.year);
 
-    match fs::copy(&tmp_file_path, &input_path) {
+    let input = match client.input(args.year, args.day) {
+        Ok(input) if !input.trim().is_empty() => input,
         Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+            eprintln!("Advent of Code returned an empty input.");
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("{}", e);
+            process::exit(1);
         }
+    };
+
+    if let Err(e) = input_path.parent().map_or(Ok(()), fs::create_dir_all) {
+        eprintln!("could not create input folder: {}", e);
+        process::exit(1);
+    }
+
+    if let Err(e) = aoc::write_atomic(&input_path, &input) {
+        eprintln!("could not write input file: {}", e);
+        process::exit(1);
     }
+
+    manifest.insert(Entry {
+        day: args.day,
+        sha256: cache::checksum(&input),
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+    });
+    // the input is saved either way, the manifest only helps to skip it next time.
+    if let Err(e) = manifest.save(&manifest_path) {
+        eprintln!("Failed to update \"{}\": {}", manifest_path.display(), e);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
 }
@@ -0,0 +1,74 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::client::Client;
+use aoc::markdown;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_fn(["-y", "--year"], aoc::parse_year)?
+            .unwrap_or_else(aoc::default_year),
+        day: args.free_from_fn(aoc::parse_day)?,
+    })
+}
+
+fn main() {
+    let Args { day, year } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("Downloading puzzle for day {}, {}...", day, year);
+
+    let html = match client.puzzle(year, day) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let parts = markdown::count_parts(&html);
+    if parts == 0 {
+        eprintln!("The page contains no puzzle description.");
+        process::exit(1);
+    }
+
+    let path = markdown::puzzle_path(year, day);
+    match markdown::save_puzzle(&path, &html, &client.puzzle_url(year, day)) {
+        Ok(_) => {
+            println!("---");
+            println!("🎄 Successfully wrote puzzle to \"{}\".", path.display());
+            if parts == 1 {
+                println!(
+                    "🎄 Part two unlocks once part one is solved. `cargo submit {} 1` adds it to this file.",
+                    day
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("could not write puzzle file: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -2,62 +2,125 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use aoc::register::{self, Change};
+use aoc::template::{self, Placeholders};
+use aoc::transaction::Transaction;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs, io,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const BIN_TEMPLATE: &str = r###"// Solves day {day_padded} of `AOC_YEAR`, or of the year passed with `--year`.
+// The solutions themselves are in `src/YYYY/{day_padded}.rs`.
+fn main() {
+    aoc::runner::solve({day});
 }
+"###;
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: u16,
+    puzzle: bool,
+    /// Module template to use instead of `template::TEMPLATE_PATH`.
+    template: Option<PathBuf>,
+    /// Print what would be created and registered without writing anything.
+    dry_run: bool,
+    /// Regenerate the module of a day that was scaffolded before.
+    force: bool,
 }
 
-fn main() {
-    let input = &aoc::read_file("inputs", DAY);
-    aoc::solve!(1, part_one, input);
-    aoc::solve!(2, part_two, input);
+/// What the puzzle page tells about a day, if `--puzzle` was passed.
+#[derive(Default)]
+struct Puzzle {
+    title: Option<String>,
+    example: Option<String>,
+    answers: Vec<Option<String>>,
 }
 
-#[cfg(test)]
-mod tests {
-    mod dayDAY {
-        use super::super::*;
-        
-        #[test]
-        fn test_part_one() {
-            let input = aoc::read_file("examples", DAY);
-            assert_eq!(part_one(&input), None);
-        }
-        
-        #[test]
-        fn test_part_two() {
-            let input = aoc::read_file("examples", DAY);
-            assert_eq!(part_two(&input), None);
-        }
-    }
+fn fetch_puzzle(year: u16, day: u8) -> Result<Puzzle, aoc::client::Error> {
+    let html = aoc::client::Client::from_env()?.puzzle(year, day)?;
+    Ok(Puzzle {
+        title: aoc::puzzle::title(&html),
+        example: aoc::puzzle::example(&html),
+        answers: aoc::puzzle::answers(&html),
+    })
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        year: args
+            .opt_value_from_fn(["-y", "--year"], aoc::parse_year)?
+            .unwrap_or_else(aoc::default_year),
+        puzzle: args.contains("--puzzle"),
+        template: args.opt_value_from_str("--template")?,
+        dry_run: args.contains("--dry-run"),
+        force: args.contains("--force"),
+        day: args.free_from_fn(aoc::parse_day)?,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// A file that scaffolding writes.
+struct Step {
+    path: PathBuf,
+    contents: String,
+    /// What writing the file does, as `(planned, done)`.
+    action: (&'static str, &'static str),
+    /// Lines added to an existing file.
+    added: Vec<String>,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+impl Step {
+    fn new(
+        path: impl Into<PathBuf>,
+        contents: String,
+        action: (&'static str, &'static str),
+    ) -> Self {
+        Step {
+            path: path.into(),
+            contents,
+            action,
+            added: Vec::new(),
+        }
+    }
+
+    fn print(&self, dry_run: bool) {
+        let action = if dry_run {
+            self.action.0
+        } else {
+            self.action.1
+        };
+        if self.added.is_empty() {
+            println!("{} \"{}\"", action, self.path.display());
+        } else {
+            println!("{} \"{}\":", action, self.path.display());
+            for line in &self.added {
+                println!("  + {}", line);
+            }
+        }
+    }
+}
+
+/// Whether `path` is missing or only holds whitespace. Files that can't be read count as
+/// filled in, so they are never overwritten.
+fn is_blank(path: &Path) -> bool {
+    match fs::read_to_string(path) {
+        Ok(contents) => contents.trim().is_empty(),
+        Err(e) => e.kind() == io::ErrorKind::NotFound,
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        day,
+        year,
+        puzzle,
+        template,
+        dry_run,
+        force,
+    } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
@@ -66,51 +129,185 @@ fn main() {
 
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = PathBuf::from(format!("src/{}/inputs/{}.txt", year, day_padded));
+    let example_path = PathBuf::from(format!("src/{}/examples/{}.txt", year, day_padded));
+    let module_path = PathBuf::from(format!("src/{}/{}.rs", year, day_padded));
+    let bin_path = PathBuf::from(format!("src/bin/{}.rs", day_padded));
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    // an explicitly passed template has to exist, the default one is optional.
+    let template_path = template
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(template::TEMPLATE_PATH));
+    let module_template = match template::load(&template_path) {
+        Ok(Some(module_template)) => {
+            println!("Using template \"{}\"", template_path.display());
+            module_template
+        }
+        Ok(None) if template.is_none() => String::from(template::MODULE_TEMPLATE),
+        Ok(None) => {
+            eprintln!("Template \"{}\" does not exist.", template_path.display());
+            process::exit(1);
+        }
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!(
+                "Failed to read template \"{}\": {}",
+                template_path.display(),
+                e
+            );
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+    let mut notes = Vec::new();
+
+    // a dry run doesn't talk to the website, the stubs are shown without the puzzle's details.
+    let puzzle = if puzzle && dry_run {
+        notes.push(String::from(
+            "Skipped downloading the puzzle, remove --dry-run to fill in its title, example and answers",
+        ));
+        Puzzle::default()
+    } else if puzzle {
+        println!("Downloading puzzle for day {}, {}...", day, year);
+        match fetch_puzzle(year, day) {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                eprintln!("Failed to download puzzle: {}", e);
+                process::exit(1);
+            }
         }
+    } else {
+        Puzzle::default()
+    };
+
+    let mut steps = Vec::new();
+
+    let module = template::render(
+        &module_template,
+        &Placeholders {
+            day,
+            year,
+            title: puzzle.title,
+            answers: puzzle.answers,
+        },
+    );
+    // an existing solution is kept unless --force is passed, so scaffolding a day again only
+    // registers it.
+    match (module_path.exists(), force) {
+        (false, _) => steps.push(Step::new(
+            &module_path,
+            module,
+            ("Would create module file", "Created module file"),
+        )),
+        (true, true) => steps.push(Step::new(
+            &module_path,
+            module,
+            ("Would regenerate module file", "Regenerated module file"),
+        )),
+        (true, false) => notes.push(format!(
+            "Kept module file \"{}\", pass --force to regenerate it",
+            module_path.display()
+        )),
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+    // the binary is shared by all years, so it only has to be created once per day.
+    if !bin_path.exists() {
+        let bin = BIN_TEMPLATE
+            .replace("{day_padded}", &day_padded)
+            .replace("{day}", &day.to_string());
+        steps.push(Step::new(
+            &bin_path,
+            bin,
+            ("Would create binary", "Created binary"),
+        ));
+    }
+
+    // inputs are never touched once they exist, not even with --force.
+    if !input_path.exists() {
+        steps.push(Step::new(
+            &input_path,
+            String::new(),
+            ("Would create empty input file", "Created empty input file"),
+        ));
+    } else if !is_blank(&input_path) {
+        notes.push(format!("Kept input file \"{}\"", input_path.display()));
+    }
+
+    match &puzzle.example {
+        _ if !is_blank(&example_path) => {
+            notes.push(format!("Kept example file \"{}\"", example_path.display()))
         }
+        Some(example) => steps.push(Step::new(
+            &example_path,
+            example.clone(),
+            ("Would write example to", "Wrote example to"),
+        )),
+        None if !example_path.exists() => steps.push(Step::new(
+            &example_path,
+            String::new(),
+            (
+                "Would create empty example file",
+                "Created empty example file",
+            ),
+        )),
+        None => {}
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for target in register::plan(year, day) {
+        match target.change {
+            Change::Update { contents, added } => steps.push(Step {
+                path: target.path,
+                contents,
+                action: ("Would update", "Updated"),
+                added,
+            }),
+            Change::Unchanged => notes.push(format!(
+                "\"{}\" already lists day {}",
+                target.path.display(),
+                day
+            )),
+            Change::Skipped(reason) => {
+                notes.push(format!("Skipped \"{}\": {}", target.path.display(), reason))
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+    }
+
+    if dry_run {
+        steps.iter().for_each(|step| step.print(true));
+        notes.iter().for_each(|note| println!("{}", note));
+        println!("---");
+        println!(
+            "🎄 Nothing was written, remove --dry-run to scaffold day {}.",
+            day
+        );
+        return;
+    }
+
+    // all or nothing: a failed step undoes the ones before it.
+    let mut transaction = Transaction::new();
+    for step in &steps {
+        if let Err(e) = transaction.write(&step.path, &step.contents) {
+            eprintln!("Failed to write \"{}\": {}", step.path.display(), e);
+            match transaction.rollback() {
+                Ok(()) => eprintln!("Rolled back, nothing was changed."),
+                Err(e) => eprintln!("Failed to roll back: {}", e),
+            }
             process::exit(1);
         }
     }
 
+    steps.iter().for_each(|step| step.print(false));
+    notes.iter().for_each(|note| println!("{}", note));
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if year == aoc::default_year() {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &day_padded
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} -- --year {}` to run your solution.",
+            &day_padded, year
+        );
+    }
 }
@@ -0,0 +1,126 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::client::Client;
+use aoc::markdown;
+use aoc::runner::{self, Status};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args
+        .opt_value_from_fn(["-y", "--year"], aoc::parse_year)?
+        .unwrap_or_else(aoc::default_year);
+    let args = Args {
+        year,
+        day: args.free_from_fn(aoc::parse_day)?,
+        part: args.free_from_fn(aoc::filter::parse_part)?,
+    };
+    Ok(args)
+}
+
+/// Rewrites the puzzle saved by `cargo read` so that it includes the part two that was just
+/// unlocked. A failure only prints a warning, the answer was accepted anyway.
+fn refresh_puzzle(client: &Client, year: u16, day: u8) {
+    let path = markdown::puzzle_path(year, day);
+    if !path.exists() {
+        return;
+    }
+
+    println!("Downloading part two of the puzzle...");
+    let saved = client
+        .puzzle(year, day)
+        .map_err(|e| e.to_string())
+        .and_then(|html| {
+            markdown::save_puzzle(&path, &html, &client.puzzle_url(year, day))
+                .map_err(|e| e.to_string())
+        });
+    match saved {
+        Ok(()) => println!("🎄 Added part two to \"{}\".", path.display()),
+        Err(e) => eprintln!(
+            "Could not update \"{}\", run `cargo read {}` to retry: {}",
+            path.display(),
+            day,
+            e
+        ),
+    }
+}
+
+fn main() {
+    let Args { day, part, year } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let day = match aoc::days::get(year, day) {
+        Some(day) => day,
+        None => {
+            eprintln!(
+                "Day {:02} of {} is not in the registry (src/days.rs).",
+                day, year
+            );
+            process::exit(1);
+        }
+    };
+
+    // fail before solving if the answer can't be submitted anyway.
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let input = match aoc::read_file(day.year, "inputs", day.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let result = runner::run_day(&day, &input, &[part], None);
+    runner::print_steps(&result);
+
+    let answer = match &result.parts[0] {
+        part if part.status == Status::Solved => part.answer.clone().unwrap_or_default(),
+        _ => {
+            eprintln!("Part {} returned no answer, nothing to submit.", part);
+            process::exit(1);
+        }
+    };
+
+    println!("---");
+    println!(
+        "Submitting {} as the answer to day {} part {} of {}...",
+        answer, day.day, part, year
+    );
+
+    match client.submit(year, day.day, part, &answer) {
+        Ok(outcome) => {
+            println!("🎄 {}", outcome);
+            if !outcome.is_accepted() {
+                process::exit(1);
+            }
+            if part == 1 {
+                refresh_puzzle(&client, year, day.day);
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
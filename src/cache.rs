@@ -0,0 +1,193 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Records the checksum and fetch time of every downloaded input in
+//! `src/YYYY/inputs/manifest.json`, so that `cargo download` can tell intact inputs from
+//! ones that were changed since:
+//!
+//! ```json
+//! {
+//!   "01": {"sha256": "…", "fetched_at": 1669870800}
+//! }
+//! ```
+
+use crate::json;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+pub fn manifest_path(year: u16) -> PathBuf {
+    crate::year_dir(year).join("inputs").join("manifest.json")
+}
+
+/// Hex encoded SHA-256 of `contents`.
+pub fn checksum(contents: &str) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, contents.as_bytes());
+    digest.as_ref().iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    pub day: u8,
+    pub sha256: String,
+    /// Seconds since the unix epoch.
+    pub fetched_at: u64,
+}
+
+/// How an input on disk compares to the manifest.
+#[derive(Debug, PartialEq)]
+pub enum Integrity {
+    /// The input wasn't downloaded by `cargo download`, or before the manifest existed.
+    Unrecorded,
+    Intact,
+    Modified,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Manifest {
+    /// Sorted by day.
+    entries: Vec<Entry>,
+}
+
+impl Manifest {
+    /// Loads the manifest at `path`. A missing file is an empty manifest.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Manifest::parse(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let entries = match json::parse(s)? {
+            json::Value::Object(entries) => entries,
+            _ => return Err(String::from("expected an object")),
+        };
+
+        let mut manifest = Manifest::default();
+        for (day, value) in entries {
+            let entry = day.parse().ok().and_then(|day| {
+                Some(Entry {
+                    day,
+                    sha256: String::from(value.get("sha256")?.as_str()?),
+                    fetched_at: value.get("fetched_at")?.as_u64()?,
+                })
+            });
+            match entry {
+                Some(entry) => manifest.insert(entry),
+                None => return Err(format!("invalid entry for day \"{}\"", day)),
+            }
+        }
+        Ok(manifest)
+    }
+
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "  \"{:02}\": {{\"sha256\": {}, \"fetched_at\": {}}}",
+                    entry.day,
+                    json::string(&entry.sha256),
+                    entry.fetched_at
+                )
+            })
+            .collect();
+
+        if entries.is_empty() {
+            String::from("{}\n")
+        } else {
+            format!("{{\n{}\n}}\n", entries.join(",\n"))
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        crate::write_atomic(path, &self.to_json())
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.day == day)
+    }
+
+    /// Adds `entry`, replacing the previous one of its day.
+    pub fn insert(&mut self, entry: Entry) {
+        match self.entries.binary_search_by_key(&entry.day, |e| e.day) {
+            Ok(i) => self.entries[i] = entry,
+            Err(i) => self.entries.insert(i, entry),
+        }
+    }
+
+    pub fn verify(&self, day: u8, contents: &str) -> Integrity {
+        match self.get(day) {
+            None => Integrity::Unrecorded,
+            Some(entry) if entry.sha256 == checksum(contents) => Integrity::Intact,
+            Some(_) => Integrity::Modified,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(
+            checksum("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut manifest = Manifest::default();
+        assert_eq!(manifest.to_json(), "{}\n");
+
+        for day in [3, 1] {
+            manifest.insert(Entry {
+                day,
+                sha256: checksum("old"),
+                fetched_at: 1,
+            });
+        }
+        manifest.insert(Entry {
+            day: 3,
+            sha256: checksum("1000\n"),
+            fetched_at: 1669870800,
+        });
+
+        let json = manifest.to_json();
+        assert!(json.starts_with("{\n  \"01\": {\"sha256\": "));
+        assert!(json.contains(",\n  \"03\": {\"sha256\": "));
+        assert_eq!(Manifest::parse(&json), Ok(manifest));
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut manifest = Manifest::default();
+        manifest.insert(Entry {
+            day: 1,
+            sha256: checksum("1000\n"),
+            fetched_at: 1669870800,
+        });
+
+        assert_eq!(manifest.verify(1, "1000\n"), Integrity::Intact);
+        assert_eq!(manifest.verify(1, "1000\n2000\n"), Integrity::Modified);
+        assert_eq!(manifest.verify(2, "1000\n"), Integrity::Unrecorded);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Manifest::parse("[]").is_err());
+        assert!(Manifest::parse("{\"01\": {\"sha256\": \"abc\"}}").is_err());
+        assert!(Manifest::parse("{\"one\": {\"sha256\": \"abc\", \"fetched_at\": 1}}").is_err());
+    }
+}
@@ -0,0 +1,704 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Requests to the Advent of Code website, authenticated with the session cookie.
+
+use crate::http::{self, Url};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, error, fmt, fs, thread};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable that replaces [`BASE_URL`], e.g. with a local server for testing.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable with the session cookie. It takes precedence over [`SESSION_FILES`].
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Files in the home directory that may hold the session cookie, in the order they are tried.
+/// The last one is shared with aoc-cli.
+pub const SESSION_FILES: [&str; 2] = [".config/aoc/session", ".adventofcode.session"];
+
+/// Minimum time between two requests to the website, across all commands.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Advent of Code asks automated tools to identify the repository they come from and who to
+/// contact about them. Both are taken from `repository` and `authors` in `Cargo.toml`, e.g.
+/// `github.com/you/advent-of-code v0.6.3 (you@example.com)`. Fails if either is missing.
+pub fn user_agent() -> Result<String, Error> {
+    format_user_agent(
+        env!("CARGO_PKG_REPOSITORY"),
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_AUTHORS"),
+    )
+}
+
+/// `authors` is separated by `:`, the way cargo passes it in `CARGO_PKG_AUTHORS`.
+fn format_user_agent(repository: &str, version: &str, authors: &str) -> Result<String, Error> {
+    let repository = repository.trim();
+    let repository = repository
+        .strip_prefix("https://")
+        .unwrap_or(repository)
+        .trim_end_matches('/');
+    if repository.is_empty() || authors.trim().is_empty() {
+        return Err(Error::NoUserAgent);
+    }
+
+    Ok(format!(
+        "{} v{} ({})",
+        repository,
+        version,
+        authors.replace(':', ", ")
+    ))
+}
+
+#[derive(Debug)]
+pub enum Error {
+    NoSession,
+    /// `repository` or `authors` is missing in `Cargo.toml`, see [`user_agent`].
+    NoUserAgent,
+    /// The website didn't accept the session cookie, usually because it expired.
+    InvalidSession,
+    Http(http::Error),
+    /// The website answered with something other than `200 OK`.
+    Status {
+        status: u16,
+        body: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoSession => write!(
+                f,
+                "No session cookie found. Set {} or write it to ~/{}.",
+                SESSION_ENV, SESSION_FILES[0]
+            ),
+            Error::NoUserAgent => write!(
+                f,
+                "Set `repository` and `authors` in Cargo.toml to the url of your repository and your contact. Advent of Code asks automated tools to identify themselves with them."
+            ),
+            Error::InvalidSession => write!(
+                f,
+                "Advent of Code rejected the session cookie, it has probably expired. Log in again, replace the cookie and verify it with `cargo aoc auth check`."
+            ),
+            Error::Http(e) => write!(f, "Request to Advent of Code failed: {}", e),
+            Error::Status { status, body } => write!(
+                f,
+                "Advent of Code responded with status {}: {}",
+                status,
+                body.lines().next().unwrap_or_default().trim()
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<http::Error> for Error {
+    fn from(e: http::Error) -> Self {
+        Error::Http(e)
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Where the session cookie was found.
+#[derive(Debug, PartialEq)]
+pub enum SessionSource {
+    Env,
+    File(PathBuf),
+}
+
+impl fmt::Display for SessionSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SessionSource::Env => write!(f, "the {} environment variable", SESSION_ENV),
+            SessionSource::File(path) => write!(f, "\"{}\"", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Session {
+    pub cookie: String,
+    pub source: SessionSource,
+}
+
+/// Finds the session cookie in [`SESSION_ENV`], then in the [`SESSION_FILES`] in the home
+/// directory. Empty values are skipped.
+pub fn session() -> Option<Session> {
+    let files: Vec<PathBuf> = home_dir()
+        .map(|home| SESSION_FILES.iter().map(|file| home.join(file)).collect())
+        .unwrap_or_default();
+    find_session(env::var(SESSION_ENV).ok(), &files)
+}
+
+fn find_session(env_value: Option<String>, files: &[PathBuf]) -> Option<Session> {
+    let non_empty = |cookie: String| {
+        let cookie = cookie.trim();
+        (!cookie.is_empty()).then(|| String::from(cookie))
+    };
+
+    if let Some(cookie) = env_value.and_then(non_empty) {
+        return Some(Session {
+            cookie,
+            source: SessionSource::Env,
+        });
+    }
+
+    files.iter().find_map(|path| {
+        let cookie = non_empty(fs::read_to_string(path).ok()?)?;
+        Some(Session {
+            cookie,
+            source: SessionSource::File(path.clone()),
+        })
+    })
+}
+
+/// Name of the logged in user in the header of any page, or `None` if nobody is logged in.
+fn parse_user(html: &str) -> Option<String> {
+    let start = html.find("<div class=\"user\">")? + "<div class=\"user\">".len();
+    let end = html[start..]
+        .find('<')
+        .map_or(html.len(), |end| start + end);
+    let user = crate::markdown::decode_entities(html[start..end].trim());
+    (!user.is_empty()).then_some(user)
+}
+
+/// Result of submitting an answer, as reported by the website.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answers were submitted too quickly. `wait` is the time left until the next attempt.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was solved before, or the previous part is still unsolved.
+    AlreadySolved,
+    /// A response this parser doesn't know, holding its text.
+    Unknown(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Outcome::Wrong => write!(f, "That's not the right answer."),
+            Outcome::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Outcome::RateLimited { wait: None } => {
+                write!(
+                    f,
+                    "You gave an answer too recently, wait before trying again."
+                )
+            }
+            Outcome::AlreadySolved => write!(
+                f,
+                "This part was already solved, or the previous part wasn't solved yet."
+            ),
+            Outcome::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+impl Outcome {
+    /// Parses the page returned for a submitted answer.
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Outcome::TooHigh
+            } else if text.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Outcome::Correct)
+    }
+}
+
+/// Text of the `<article>` that holds the message, with tags removed and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = match html.find("<article") {
+        Some(start) => {
+            let end = html[start..]
+                .find("</article>")
+                .map_or(html.len(), |end| start + end);
+            &html[start..end]
+        }
+        None => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = text[start..].find(" left to wait")? + start;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let unit_start = amount.find(|c: char| !c.is_ascii_digit())?;
+            let value: u64 = amount[..unit_start].parse().ok()?;
+            match &amount[unit_start..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Spaces out requests to the website. The time of the last request is kept in a file, so the
+/// interval holds across processes, e.g. when a shell loop downloads several days.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: PathBuf, interval: Duration) -> Self {
+        Throttle { path, interval }
+    }
+
+    /// `target/aoc-last-request`, next to the benchmark history.
+    pub fn last_request_path() -> PathBuf {
+        let target = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| String::from("target"));
+        PathBuf::from(target).join("aoc-last-request")
+    }
+
+    /// Sleeps until the interval has passed since the last request, then records a new one.
+    pub fn wait(&self) {
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|last| last.trim().parse().ok());
+        let delay = delay(last, now_millis(), self.interval);
+        if !delay.is_zero() {
+            eprintln!(
+                "Waiting {:.1}s between requests to Advent of Code...",
+                delay.as_secs_f64()
+            );
+            thread::sleep(delay);
+        }
+
+        // a failed write only means the next request isn't throttled.
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&self.path, now_millis().to_string());
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Time left to wait if the last request was at `last`, both in milliseconds since the epoch.
+/// A `last` in the future, e.g. after the clock was changed, waits at most `interval`.
+fn delay(last: Option<u64>, now: u64, interval: Duration) -> Duration {
+    match last {
+        Some(last) => {
+            let elapsed = Duration::from_millis(now.saturating_sub(last));
+            interval.saturating_sub(elapsed)
+        }
+        None => Duration::ZERO,
+    }
+}
+
+pub struct Client {
+    base: Url,
+    session: String,
+    user_agent: String,
+    throttle: Option<Throttle>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Result<Self, Error> {
+        Ok(Client {
+            base: Url::parse(base_url)?,
+            session: String::from(session),
+            user_agent: String::from(user_agent),
+            throttle: None,
+        })
+    }
+
+    /// Uses [`BASE_URL_ENV`] if set, the session cookie from [`session`] and the
+    /// [`user_agent`] of this project. Requests are at least [`MIN_REQUEST_INTERVAL`] apart.
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| String::from(BASE_URL));
+        let user_agent = user_agent()?;
+        let client = Client::new(
+            &base_url,
+            &session().ok_or(Error::NoSession)?.cookie,
+            &user_agent,
+        )?;
+        Ok(client.throttled(Throttle::new(
+            Throttle::last_request_path(),
+            MIN_REQUEST_INTERVAL,
+        )))
+    }
+
+    pub fn throttled(self, throttle: Throttle) -> Self {
+        Client {
+            throttle: Some(throttle),
+            ..self
+        }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &str) -> Result<http::Response, Error> {
+        let cookie = format!("session={}", self.session);
+        let mut headers = vec![
+            ("Cookie", cookie.as_str()),
+            ("User-Agent", self.user_agent.as_str()),
+        ];
+        if !body.is_empty() {
+            headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+        }
+
+        if let Some(throttle) = &self.throttle {
+            throttle.wait();
+        }
+
+        let response = http::request(method, &self.base, path, &headers, body)?;
+        // logged out requests are redirected to the login page or asked to log in.
+        let redirected_to_login = response
+            .header("Location")
+            .is_some_and(|location| location.contains("/auth/login"));
+        if redirected_to_login
+            || (response.status != 200 && response.body.to_lowercase().contains("log in"))
+        {
+            return Err(Error::InvalidSession);
+        }
+        if response.status != 200 {
+            return Err(Error::Status {
+                status: response.status,
+                body: response.body,
+            });
+        }
+        Ok(response)
+    }
+
+    /// Name of the user the session cookie belongs to, as shown on the pages of `year`.
+    pub fn user(&self, year: u16) -> Result<String, Error> {
+        let response = self.request("GET", &format!("/{}", year), "")?;
+        parse_user(&response.body).ok_or(Error::InvalidSession)
+    }
+
+    /// URL of the puzzle page of `day`, for resolving its links.
+    pub fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base, year, day)
+    }
+
+    /// Downloads the puzzle page of `day`, which includes part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        let response = self.request("GET", &format!("/{}/day/{}", year, day), "")?;
+        Ok(response.body)
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let response = self.request("GET", &format!("/{}/day/{}/input", year, day), "")?;
+        Ok(response.body)
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, Error> {
+        let body = format!("level={}&answer={}", part, http::form_encode(answer));
+        let response = self.request("POST", &format!("/{}/day/{}/answer", year, day), &body)?;
+        Ok(Outcome::parse(&response.body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::serve_once;
+
+    const USER_AGENT: &str = "github.com/you/advent-of-code v1.0.0 (you@example.com)";
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html><html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_user_agent() {
+        assert_eq!(
+            format_user_agent(
+                "https://github.com/you/advent-of-code/",
+                "1.0.0",
+                "You <you@example.com>:Someone else"
+            )
+            .unwrap(),
+            "github.com/you/advent-of-code v1.0.0 (You <you@example.com>, Someone else)"
+        );
+        assert!(matches!(
+            format_user_agent("", "1.0.0", "You <you@example.com>"),
+            Err(Error::NoUserAgent)
+        ));
+        assert!(matches!(
+            format_user_agent("https://github.com/you/advent-of-code", "1.0.0", ""),
+            Err(Error::NoUserAgent)
+        ));
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            Outcome::parse(&page("That's the right answer! You are <em>one gold star</em> closer to saving your vacation. <a href=\"/2022/day/1#part2\">[Continue to Part Two]</a>")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2022/about\">about page</a>. Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer. If you're stuck, make sure you're using the full input data.")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Outcome::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            Outcome::parse(&page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(
+            Outcome::parse(&page("Something <em>new</em>.")),
+            Outcome::Unknown(String::from("Something new."))
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let body = page("That's not the right answer; your answer is too low.");
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let (url, server) = serve_once(response);
+
+        let client = Client::new(&url, "abc123", USER_AGENT).unwrap();
+        assert_eq!(client.submit(2022, 1, 2, "4 2").unwrap(), Outcome::TooLow);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+        assert!(request.contains(&format!("\r\nUser-Agent: {}\r\n", USER_AGENT)));
+        assert!(request.contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=4+2"));
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = serve_once(String::from(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n6\r\n1000\n2\r\n5\r\n000\n\n\r\n0\r\n\r\n",
+        ));
+
+        let client = Client::new(&url, "abc123", USER_AGENT).unwrap();
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+        assert!(request.contains(&format!("\r\nUser-Agent: {}\r\n", USER_AGENT)));
+        assert!(!request.contains("Content-Type"));
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let (url, server) = serve_once(String::from(
+            "HTTP/1.1 200 OK\r\nContent-Length: 44\r\n\r\n<article class=\"day-desc\"><p>a</p></article>",
+        ));
+
+        let client = Client::new(&url, "abc123", USER_AGENT).unwrap();
+        assert_eq!(client.puzzle_url(2022, 1), format!("{}/2022/day/1", url));
+        assert_eq!(
+            client.puzzle(2022, 1).unwrap(),
+            "<article class=\"day-desc\"><p>a</p></article>"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1 HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn test_find_session() {
+        let dir = env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let files = [dir.join("session"), dir.join(".adventofcode.session")];
+        fs::write(&files[0], "\n").unwrap();
+        fs::write(&files[1], "from-file\n").unwrap();
+
+        assert_eq!(
+            find_session(Some(String::from(" from-env ")), &files),
+            Some(Session {
+                cookie: String::from("from-env"),
+                source: SessionSource::Env
+            })
+        );
+        // empty values fall through to the next source.
+        assert_eq!(
+            find_session(Some(String::new()), &files),
+            Some(Session {
+                cookie: String::from("from-file"),
+                source: SessionSource::File(files[1].clone())
+            })
+        );
+
+        fs::write(&files[0], "first").unwrap();
+        assert_eq!(
+            find_session(None, &files).map(|session| session.source),
+            Some(SessionSource::File(files[0].clone()))
+        );
+        assert_eq!(find_session(None, &[dir.join("missing")]), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_user() {
+        let body = "<header><div class=\"user\">Eric &amp; Co <span class=\"star-count\">50*</span></div></header>";
+        let (url, server) = serve_once(format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        ));
+
+        let client = Client::new(&url, "abc123", USER_AGENT).unwrap();
+        assert_eq!(client.user(2022).unwrap(), "Eric & Co");
+        assert!(server.join().unwrap().starts_with("GET /2022 HTTP/1.1\r\n"));
+
+        assert_eq!(
+            parse_user("<div class=\"user\">(anonymous user #1)</div>").as_deref(),
+            Some("(anonymous user #1)")
+        );
+        assert_eq!(
+            parse_user("<a href=\"/2022/auth/login\">[Log In]</a>"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_session() {
+        let (url, server) = serve_once(String::from(
+            "HTTP/1.1 400 Bad Request\r\nContent-Length: 69\r\n\r\nPuzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        ));
+        let client = Client::new(&url, "expired", USER_AGENT).unwrap();
+        assert!(matches!(client.input(2022, 1), Err(Error::InvalidSession)));
+        server.join().unwrap();
+
+        let (url, server) = serve_once(String::from(
+            "HTTP/1.1 302 Found\r\nLocation: /2022/auth/login\r\nContent-Length: 0\r\n\r\n",
+        ));
+        let client = Client::new(&url, "expired", USER_AGENT).unwrap();
+        assert!(matches!(
+            client.submit(2022, 1, 1, "1"),
+            Err(Error::InvalidSession)
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_delay() {
+        let interval = Duration::from_secs(5);
+        assert_eq!(delay(None, 10_000, interval), Duration::ZERO);
+        assert_eq!(delay(Some(9_000), 10_000, interval), Duration::from_secs(4));
+        assert_eq!(delay(Some(4_000), 10_000, interval), Duration::ZERO);
+        assert_eq!(delay(Some(20_000), 10_000, interval), interval);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let path = env::temp_dir().join(format!("aoc-throttle-{}", std::process::id()));
+        let throttle = Throttle::new(path.clone(), Duration::from_millis(50));
+
+        throttle.wait();
+        let first: u64 = fs::read_to_string(&path).unwrap().parse().unwrap();
+        throttle.wait();
+        let second: u64 = fs::read_to_string(&path).unwrap().parse().unwrap();
+        assert!(second - first >= 50);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_submit_status() {
+        let (url, server) = serve_once(String::from(
+            "HTTP/1.1 400 Bad Request\r\nContent-Length: 31\r\n\r\nPuzzle inputs differ by user.\r\n",
+        ));
+
+        let client = Client::new(&url, "expired", USER_AGENT).unwrap();
+        match client.submit(2022, 1, 1, "1") {
+            Err(e @ Error::Status { status: 400, .. }) => assert_eq!(
+                e.to_string(),
+                "Advent of Code responded with status 400: Puzzle inputs differ by user."
+            ),
+            other => panic!("expected a status error, got {:?}", other),
+        }
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+/*
+ * This file contains the registry of implemented days.
+ * `cargo scaffold` adds a line to `all()` (and a matching module in `src/YYYY/mod.rs`) for every new day,
+ * and a module below for every new year.
+ */
+use crate::Day;
+
+#[path = "2022/mod.rs"]
+pub mod y2022;
+
+/// All implemented days, ordered by year and day.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<y2022::day01::Day01>(),
+        Day::of::<y2022::day02::Day02>(),
+        Day::of::<y2022::day03::Day03>(),
+        Day::of::<y2022::day04::Day04>(),
+        Day::of::<y2022::day05::Day05>(),
+        Day::of::<y2022::day06::Day06>(),
+        Day::of::<y2022::day07::Day07>(),
+        Day::of::<y2022::day08::Day08>(),
+        Day::of::<y2022::day09::Day09>(),
+        Day::of::<y2022::day10::Day10>(),
+        Day::of::<y2022::day11::Day11>(),
+    ]
+}
+
+/// All implemented days of `year`.
+pub fn of_year(year: u16) -> Vec<Day> {
+    all().into_iter().filter(|d| d.year == year).collect()
+}
+
+/// Looks up a single day in the registry.
+pub fn get(year: u16, day: u8) -> Option<Day> {
+    all().into_iter().find(|d| d.year == year && d.day == day)
+}
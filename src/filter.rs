@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::parse_day;
+use crate::runner::{DayResult, Status};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A set of days such as `3,5..8`. Ranges include both ends.
+#[derive(Debug, PartialEq)]
+pub struct Days(Vec<RangeInclusive<u8>>);
+
+impl Days {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.iter().any(|range| range.contains(&day))
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| {
+                let range = match item.split_once("..") {
+                    Some((start, end)) => {
+                        parse_day(start)?..=parse_day(end.strip_prefix('=').unwrap_or(end))?
+                    }
+                    None => parse_day(item).map(|day| day..=day)?,
+                };
+
+                if range.is_empty() {
+                    return Err(format!("range \"{}\" is empty", item.trim()));
+                }
+                Ok(range)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Days)
+    }
+}
+
+/// Parses the part passed to `--part` or `cargo submit`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(String::from("part must be 1 or 2")),
+    }
+}
+
+/// Selection of days and parts passed as `--days`, `--part` and `--only-solved`.
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub days: Option<Days>,
+    pub part: Option<u8>,
+    /// Leave out days without input and parts that returned no answer.
+    pub only_solved: bool,
+}
+
+impl Filter {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Filter {
+            days: args.opt_value_from_str("--days")?,
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            only_solved: args.contains("--only-solved"),
+        })
+    }
+
+    /// Whether the filter restricts which days or parts are run.
+    pub fn is_partial(&self) -> bool {
+        self.days.is_some() || self.part.is_some()
+    }
+
+    pub fn includes_day(&self, day: u8) -> bool {
+        self.days.as_ref().is_none_or(|days| days.contains(day))
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    /// Applies `--only-solved`. Returns `false` if nothing of `result` is left to show.
+    pub fn retain(&self, result: &mut DayResult) -> bool {
+        if self.only_solved {
+            result.parts.retain(|part| part.status == Status::Solved);
+        }
+        !result.parts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        let days: Days = "3, 5..8,10..=11".parse().unwrap();
+        let selected: Vec<u8> = (1..=25).filter(|day| days.contains(*day)).collect();
+        assert_eq!(selected, vec![3, 5, 6, 7, 8, 10, 11]);
+
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+        assert!("8..5".parse::<Days>().is_err());
+        assert!("3,".parse::<Days>().is_err());
+        assert!("a..b".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn test_from_args() {
+        let mut args = pico_args::Arguments::from_vec(
+            ["--days", "1..3", "--part", "2", "--only-solved"]
+                .iter()
+                .map(Into::into)
+                .collect(),
+        );
+        let filter = Filter::from_args(&mut args).unwrap();
+
+        assert!(filter.includes_day(2));
+        assert!(!filter.includes_day(4));
+        assert_eq!(filter.parts(), vec![2]);
+        assert!(filter.only_solved);
+        assert!(filter.is_partial());
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--part".into(), "3".into()]);
+        assert!(Filter::from_args(&mut args).is_err());
+    }
+}
@@ -0,0 +1,363 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::json;
+use crate::runner::{DayResult, Status};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_THRESHOLD: f64 = 20.0;
+
+/// Slowdowns smaller than this are timer noise and never count as regressions.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+pub struct Timing {
+    pub day: u8,
+    /// `0` is the parse step.
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+/// Timings of a single `cargo all` run, stored as one line of the history file.
+pub struct Run {
+    pub commit: String,
+    pub timestamp: u64,
+    pub profile: String,
+    pub baseline: Option<String>,
+    pub timings: Vec<Timing>,
+}
+
+impl Run {
+    pub fn from_results(results: &[DayResult], baseline: Option<String>) -> Self {
+        let timings = results
+            .iter()
+            .flat_map(|day| {
+                let parse = day
+                    .parse
+                    .as_ref()
+                    .filter(|parse| !parse.timed_out)
+                    .map(|parse| Timing {
+                        day: day.day,
+                        part: 0,
+                        elapsed: parse.elapsed,
+                    });
+                let parts = day
+                    .parts
+                    .iter()
+                    .filter(|part| part.status == Status::Solved)
+                    .map(|part| Timing {
+                        day: day.day,
+                        part: part.part,
+                        elapsed: part.elapsed,
+                    });
+                parse.into_iter().chain(parts)
+            })
+            .collect();
+
+        Run {
+            commit: current_commit(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            profile: String::from(current_profile()),
+            baseline,
+            timings,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let timings: Vec<String> = self
+            .timings
+            .iter()
+            .map(|t| {
+                format!(
+                    "{{\"day\": {}, \"part\": {}, \"elapsed_ns\": {}}}",
+                    t.day,
+                    t.part,
+                    t.elapsed.as_nanos()
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"commit\": {}, \"timestamp\": {}, \"profile\": {}, \"baseline\": {}, \"timings\": [{}]}}",
+            json::string(&self.commit),
+            self.timestamp,
+            json::string(&self.profile),
+            self.baseline.as_deref().map_or(String::from("null"), json::string),
+            timings.join(", ")
+        )
+    }
+
+    pub fn from_json(line: &str) -> Option<Self> {
+        let value = json::parse(line).ok()?;
+
+        let timings = value
+            .get("timings")?
+            .as_array()?
+            .iter()
+            .map(|t| {
+                Some(Timing {
+                    day: t.get("day")?.as_u64()? as u8,
+                    part: t.get("part")?.as_u64()? as u8,
+                    elapsed: Duration::from_nanos(t.get("elapsed_ns")?.as_u64()?),
+                })
+            })
+            .collect::<Option<Vec<Timing>>>()?;
+
+        Some(Run {
+            commit: String::from(value.get("commit")?.as_str()?),
+            timestamp: value.get("timestamp")?.as_u64()?,
+            profile: String::from(value.get("profile")?.as_str()?),
+            baseline: value.get("baseline")?.as_str().map(String::from),
+            timings,
+        })
+    }
+
+    fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.part == part)
+            .map(|t| t.elapsed)
+    }
+}
+
+pub struct Delta {
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Delta {
+    /// Relative change in percent. Positive values mean the part got slower.
+    pub fn change(&self) -> f64 {
+        let before = self.before.as_secs_f64();
+        if before == 0_f64 {
+            return 0_f64;
+        }
+        (self.after.as_secs_f64() - before) / before * 100_f64
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.after.saturating_sub(self.before) > NOISE_FLOOR && self.change() > threshold
+    }
+}
+
+/// Every year keeps its own history, as day numbers repeat across years.
+pub fn history_path(year: u16) -> PathBuf {
+    let target = std::env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| String::from("target"));
+    PathBuf::from(target).join(format!("aoc-bench-history-{}.jsonl", year))
+}
+
+/// Loads all runs from the history file. A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<Run>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.lines().filter_map(Run::from_json).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append(path: &Path, run: &Run) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", run.to_json())
+}
+
+/// Finds the run to compare against: the latest run saved under `baseline` if given,
+/// otherwise the latest run. Only runs built with the same profile are considered.
+pub fn find_reference<'a>(
+    history: &'a [Run],
+    profile: &str,
+    baseline: Option<&str>,
+) -> Option<&'a Run> {
+    history
+        .iter()
+        .rev()
+        .filter(|run| run.profile == profile)
+        .find(|run| baseline.is_none() || run.baseline.as_deref() == baseline)
+}
+
+pub fn compare(reference: &Run, current: &Run) -> Vec<Delta> {
+    current
+        .timings
+        .iter()
+        .filter_map(|t| {
+            Some(Delta {
+                day: t.day,
+                part: t.part,
+                before: reference.get(t.day, t.part)?,
+                after: t.elapsed,
+            })
+        })
+        .collect()
+}
+
+pub fn format_comparison(reference: &Run, deltas: &[Delta], threshold: f64, now: u64) -> String {
+    let mut lines = vec![format!(
+        "{}Compared to {}{} {}({}, {}){}",
+        ANSI_BOLD,
+        reference.commit,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        reference
+            .baseline
+            .as_ref()
+            .map_or(String::from("previous run"), |name| format!(
+                "baseline \"{}\"",
+                name
+            )),
+        format_age(now.saturating_sub(reference.timestamp)),
+        ANSI_RESET
+    )];
+
+    for delta in deltas {
+        let step = match delta.part {
+            0 => String::from("Parse"),
+            part => format!("Part {}", part),
+        };
+        let line = format!(
+            "Day {:02} {}: {:.2?} -> {:.2?} ({:+.1}%)",
+            delta.day,
+            step,
+            delta.before,
+            delta.after,
+            delta.change()
+        );
+
+        if delta.is_regression(threshold) {
+            lines.push(format!("{}{} REGRESSION{}", ANSI_RED, line, ANSI_RESET));
+        } else {
+            lines.push(line);
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn format_age(seconds: u64) -> String {
+    match seconds {
+        0..=59 => String::from("just now"),
+        60..=3599 => format!("{}m ago", seconds / 60),
+        3600..=86399 => format!("{}h ago", seconds / 3600),
+        _ => format!("{}d ago", seconds / 86400),
+    }
+}
+
+fn current_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+fn current_commit() -> String {
+    let output = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match output(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match output(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", commit),
+            _ => commit,
+        },
+        None => String::from("unknown"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(commit: &str, baseline: Option<&str>, timings: &[(u8, u8, u64)]) -> Run {
+        Run {
+            commit: String::from(commit),
+            timestamp: 1_000,
+            profile: String::from("release"),
+            baseline: baseline.map(String::from),
+            timings: timings
+                .iter()
+                .map(|(day, part, micros)| Timing {
+                    day: *day,
+                    part: *part,
+                    elapsed: Duration::from_micros(*micros),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let original = run("abc1234", Some("v1"), &[(1, 1, 10), (8, 2, 250)]);
+        let parsed = Run::from_json(&original.to_json()).unwrap();
+
+        assert_eq!(parsed.commit, "abc1234");
+        assert_eq!(parsed.baseline.as_deref(), Some("v1"));
+        assert_eq!(parsed.get(8, 2), Some(Duration::from_micros(250)));
+        assert!(Run::from_json("not json").is_none());
+    }
+
+    #[test]
+    fn test_find_reference() {
+        let history = vec![
+            run("a", Some("v1"), &[]),
+            run("b", None, &[]),
+            run("c", None, &[]),
+        ];
+
+        assert_eq!(
+            find_reference(&history, "release", None).unwrap().commit,
+            "c"
+        );
+        assert_eq!(
+            find_reference(&history, "release", Some("v1"))
+                .unwrap()
+                .commit,
+            "a"
+        );
+        assert!(find_reference(&history, "release", Some("v2")).is_none());
+        assert!(find_reference(&history, "debug", None).is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        let reference = run(
+            "a",
+            None,
+            &[(1, 0, 20), (1, 1, 100), (1, 2, 100), (2, 2, 1)],
+        );
+        let current = run(
+            "b",
+            None,
+            &[(1, 0, 25), (1, 1, 90), (1, 2, 180), (2, 1, 10), (2, 2, 2)],
+        );
+
+        let deltas = compare(&reference, &current);
+        assert_eq!(deltas.len(), 4);
+        assert!(!deltas[1].is_regression(DEFAULT_THRESHOLD));
+        assert!(deltas[2].is_regression(DEFAULT_THRESHOLD));
+        assert!(!deltas[2].is_regression(90_f64));
+        // +100%, but below the noise floor.
+        assert!(!deltas[3].is_regression(DEFAULT_THRESHOLD));
+
+        let report = format_comparison(&reference, &deltas, DEFAULT_THRESHOLD, 1_000 + 7_200);
+        assert!(report.contains("previous run, 2h ago"));
+        assert!(report.contains("Day 01 Parse: 20.00µs -> 25.00µs (+25.0%)\n"));
+        assert!(report.contains("Day 01 Part 2: 100.00µs -> 180.00µs (+80.0%) REGRESSION"));
+    }
+}
@@ -0,0 +1,375 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Just enough HTTP/1.1 to talk to Advent of Code. `https://` URLs go through rustls, plain
+//! `http://` URLs are supported so that tests can run against a local server.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::time::Duration;
+use std::{error, fmt};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidUrl(String),
+    Io(io::Error),
+    InvalidResponse(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidUrl(url) => write!(f, "\"{}\" is not a valid http(s) URL", url),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::InvalidResponse(message) => write!(f, "invalid response: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Url {
+    pub secure: bool,
+    pub host: String,
+    pub port: u16,
+    /// Path prefix without a trailing slash, empty for the root.
+    pub path: String,
+}
+
+impl Url {
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidUrl(String::from(s));
+        let (secure, rest) = match s.split_once("://") {
+            Some(("https", rest)) => (true, rest),
+            Some(("http", rest)) => (false, rest),
+            _ => return Err(invalid()),
+        };
+
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, ""),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+            None => (authority, if secure { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Url {
+            secure,
+            host: String::from(host),
+            port,
+            path: String::from(path.trim_end_matches('/')),
+        })
+    }
+
+    fn host_header(&self) -> String {
+        match (self.secure, self.port) {
+            (true, 443) | (false, 80) => self.host.clone(),
+            _ => format!("{}:{}", self.host, self.port),
+        }
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scheme = if self.secure { "https" } else { "http" };
+        write!(f, "{}://{}{}", scheme, self.host_header(), self.path)
+    }
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Sends a single request to `path` below `base` and reads the whole response.
+/// The connection is closed afterwards.
+pub fn request(
+    method: &str,
+    base: &Url,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: &str,
+) -> Result<Response, Error> {
+    let mut message = format!(
+        "{} {}{} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        method,
+        base.path,
+        path,
+        base.host_header()
+    );
+    for (name, value) in headers {
+        message.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if !body.is_empty() || method == "POST" {
+        message.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    message.push_str("\r\n");
+    message.push_str(body);
+
+    let stream = TcpStream::connect((base.host.as_str(), base.port))?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    if base.secure {
+        exchange(tls(stream, &base.host)?, &message)
+    } else {
+        exchange(stream, &message)
+    }
+}
+
+fn tls(stream: TcpStream, host: &str) -> Result<impl Read + Write, Error> {
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let name = rustls::pki_types::ServerName::try_from(String::from(host))
+        .map_err(|_| Error::InvalidUrl(String::from(host)))?;
+    let connection = rustls::ClientConnection::new(Arc::new(config), name)
+        .map_err(|e| Error::Io(io::Error::other(e)))?;
+
+    Ok(rustls::StreamOwned::new(connection, stream))
+}
+
+fn exchange(mut stream: impl Read + Write, message: &str) -> Result<Response, Error> {
+    stream.write_all(message.as_bytes())?;
+    stream.flush()?;
+    read_response(&mut BufReader::new(stream))
+}
+
+fn read_line(reader: &mut impl BufRead) -> Result<String, Error> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(Error::InvalidResponse(String::from(
+            "connection closed early",
+        )));
+    }
+    Ok(String::from(line.trim_end_matches(['\r', '\n'])))
+}
+
+/// Reads a response framed by `Content-Length`, chunked encoding or the end of the stream.
+pub fn read_response(reader: &mut impl BufRead) -> Result<Response, Error> {
+    let status_line = read_line(reader)?;
+    let status = status_line
+        .split(' ')
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| Error::InvalidResponse(format!("status line \"{}\"", status_line)))?;
+
+    let mut headers = Vec::new();
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((String::from(name.trim()), String::from(value.trim())));
+        }
+    }
+
+    let mut response = Response {
+        status,
+        headers,
+        body: String::new(),
+    };
+
+    let mut body = Vec::new();
+    if response
+        .header("Transfer-Encoding")
+        .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"))
+    {
+        loop {
+            let line = read_line(reader)?;
+            let size = line.split(';').next().unwrap_or_default().trim();
+            let size = usize::from_str_radix(size, 16)
+                .map_err(|_| Error::InvalidResponse(format!("chunk size \"{}\"", line)))?;
+            if size == 0 {
+                // skip trailers.
+                while !read_line(reader)?.is_empty() {}
+                break;
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            read_line(reader)?;
+        }
+    } else if let Some(length) = response.header("Content-Length") {
+        let length = length
+            .parse()
+            .map_err(|_| Error::InvalidResponse(format!("content length \"{}\"", length)))?;
+        body.resize(length, 0);
+        reader.read_exact(&mut body)?;
+    } else {
+        match reader.read_to_end(&mut body) {
+            // servers may close TLS connections without a close_notify.
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+            result => {
+                result?;
+            }
+        }
+    }
+
+    response.body = String::from_utf8(body)
+        .map_err(|_| Error::InvalidResponse(String::from("body is not UTF-8")))?;
+    Ok(response)
+}
+
+/// Encodes `s` for an `application/x-www-form-urlencoded` body.
+pub fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                String::from(byte as char)
+            }
+            b' ' => String::from("+"),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves `response` to a single request on a free local port. Returns the URL of the
+    /// server and a handle that yields the raw request.
+    pub(crate) fn serve_once(response: String) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.strip_prefix("Content-Length: ") {
+                    length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (url, server)
+    }
+
+    #[test]
+    fn test_request() {
+        let (url, server) = serve_once(String::from(
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        ));
+        let url = Url::parse(&format!("{}/prefix/", url)).unwrap();
+
+        let response = request("GET", &url, "/path", &[("X-Test", "1")], "").unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, "ok"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /prefix/path HTTP/1.1\r\n"));
+        assert!(request.contains(&format!("\r\nHost: 127.0.0.1:{}\r\n", url.port)));
+        assert!(request.contains("\r\nX-Test: 1\r\n"));
+        assert!(!request.contains("Content-Length"));
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(
+            Url::parse("https://adventofcode.com").unwrap(),
+            Url {
+                secure: true,
+                host: String::from("adventofcode.com"),
+                port: 443,
+                path: String::new(),
+            }
+        );
+
+        let url = Url::parse("http://127.0.0.1:8080/aoc/").unwrap();
+        assert_eq!((url.port, url.path.as_str()), (8080, "/aoc"));
+        assert_eq!(url.host_header(), "127.0.0.1:8080");
+        assert_eq!(url.to_string(), "http://127.0.0.1:8080/aoc");
+        assert_eq!(
+            Url::parse("https://adventofcode.com/").unwrap().to_string(),
+            "https://adventofcode.com"
+        );
+
+        assert!(Url::parse("ftp://example.com").is_err());
+        assert!(Url::parse("http://:80").is_err());
+        assert!(Url::parse("http://example.com:port").is_err());
+    }
+
+    #[test]
+    fn test_read_response() {
+        let raw = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nContent-Type: text/plain\r\n\r\nhello";
+        let response = read_response(&mut raw.as_bytes()).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-type"), Some("text/plain"));
+        assert_eq!(response.body, "hello");
+
+        let raw = "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n\
+                   4\r\nnot \r\n5;x=y\r\nfound\r\n0\r\n\r\n";
+        let response = read_response(&mut raw.as_bytes()).unwrap();
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "not found");
+
+        let raw = "HTTP/1.0 200 OK\r\n\r\nuntil the end";
+        assert_eq!(
+            read_response(&mut raw.as_bytes()).unwrap().body,
+            "until the end"
+        );
+
+        assert!(read_response(&mut "garbage".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("a b&c=1"), "a+b%26c%3D1");
+        assert_eq!(form_encode("1234"), "1234");
+    }
+}
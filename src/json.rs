@@ -0,0 +1,227 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Just enough JSON to read back the files written by the template.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// Kept as written so integers don't lose precision.
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// Quotes and escapes `s` as a JSON string.
+pub fn string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+pub fn parse(s: &str) -> Result<Value, String> {
+    let mut chars = s.chars().peekable();
+    let value = parse_value(&mut chars)?;
+
+    skip_whitespace(&mut chars);
+    match chars.next() {
+        None => Ok(value),
+        Some(c) => Err(format!("unexpected trailing character '{}'", c)),
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(format!("expected '{}', found '{}'", expected, c)),
+        None => Err(format!("expected '{}', found end of input", expected)),
+    }
+}
+
+fn parse_literal(
+    chars: &mut Peekable<Chars>,
+    literal: &str,
+    value: Value,
+) -> Result<Value, String> {
+    for expected in literal.chars() {
+        if chars.next() != Some(expected) {
+            return Err(format!("invalid literal, expected \"{}\"", literal));
+        }
+    }
+    Ok(value)
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Result<Value, String> {
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('n') => parse_literal(chars, "null", Value::Null),
+        Some('t') => parse_literal(chars, "true", Value::Bool(true)),
+        Some('f') => parse_literal(chars, "false", Value::Bool(false)),
+        Some('"') => parse_string(chars).map(Value::String),
+        Some('[') => {
+            chars.next();
+            let mut values = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Ok(Value::Array(values));
+            }
+            loop {
+                values.push(parse_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => continue,
+                    Some(']') => return Ok(Value::Array(values)),
+                    _ => return Err(String::from("expected ',' or ']' in array")),
+                }
+            }
+        }
+        Some('{') => {
+            chars.next();
+            let mut entries = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Ok(Value::Object(entries));
+            }
+            loop {
+                skip_whitespace(chars);
+                let key = parse_string(chars)?;
+                expect(chars, ':')?;
+                entries.push((key, parse_value(chars)?));
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => continue,
+                    Some('}') => return Ok(Value::Object(entries)),
+                    _ => return Err(String::from("expected ',' or '}' in object")),
+                }
+            }
+        }
+        Some(c) if *c == '-' || c.is_ascii_digit() => {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|c| "+-.eE".contains(*c) || c.is_ascii_digit()) {
+                number.push(c);
+            }
+            Ok(Value::Number(number))
+        }
+        Some(c) => Err(format!("unexpected character '{}'", c)),
+        None => Err(String::from("unexpected end of input")),
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    expect(chars, '"')?;
+
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => match chars.next() {
+                Some('n') => s.push('\n'),
+                Some('r') => s.push('\r'),
+                Some('t') => s.push('\t'),
+                Some('b') => s.push('\u{8}'),
+                Some('f') => s.push('\u{c}'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let c = u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("invalid unicode escape \"\\u{}\"", hex))?;
+                    s.push(c);
+                }
+                Some(c) => s.push(c),
+                None => return Err(String::from("unterminated string")),
+            },
+            Some(c) => s.push(c),
+            None => return Err(String::from("unterminated string")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let value =
+            parse(r#" {"a": [1, -2.5e3, "x\"y\u0041"], "b": {"c": null, "d": true}} "#).unwrap();
+
+        let a = value.get("a").and_then(Value::as_array).unwrap();
+        assert_eq!(a[0].as_u64(), Some(1));
+        assert_eq!(a[1], Value::Number(String::from("-2.5e3")));
+        assert_eq!(a[2].as_str(), Some("x\"yA"));
+        assert_eq!(value.get("b").and_then(|b| b.get("c")), Some(&Value::Null));
+        assert_eq!(
+            value.get("b").and_then(|b| b.get("d")),
+            Some(&Value::Bool(true))
+        );
+        assert_eq!(parse("[]"), Ok(Value::Array(vec![])));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("{\"a\": 1").is_err());
+        assert!(parse("[1, 2] 3").is_err());
+        assert!(parse("nul").is_err());
+    }
+
+    #[test]
+    fn test_string_roundtrip() {
+        let s = "a \"b\"\n\\c\u{1}";
+        assert_eq!(string("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+        assert_eq!(parse(&string(s)), Ok(Value::String(String::from(s))));
+    }
+}
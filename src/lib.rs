@@ -3,92 +3,308 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+extern crate self as aoc;
+
+use std::any::Any;
 use std::env;
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod days;
+pub mod filter;
 pub mod helpers;
+pub mod history;
+pub mod http;
+pub mod json;
+pub mod markdown;
+pub mod memory;
+pub mod puzzle;
+pub mod readme;
+pub mod register;
+pub mod report;
+pub mod runner;
+pub mod template;
+pub mod transaction;
+pub mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RED: &str = "\x1b[31m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+/// A puzzle solution for a single day.
+///
+/// Implement this on a unit struct in `src/YYYY/NN.rs` and add the struct to the registry in
+/// `./days.rs` so that the runner and tests can find it.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    /// The input after parsing. Use `String` to hand the raw input to both parts.
+    type Parsed: 'static;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Parses the input once. The runner times this separately from the parts.
+    fn parse(input: &str) -> Self::Parsed;
+    fn part_one(parsed: &Self::Parsed) -> Option<Self::PartOne>;
+    fn part_two(parsed: &Self::Parsed) -> Option<Self::PartTwo>;
+}
+
+/// Parsed input of a [`Day`], holding the [`Solution::Parsed`] value of that day.
+pub type Parsed = Box<dyn Any>;
+
+/// Solves a part of a parsed input. The solution is called through `run`, so that callers can
+/// measure it without the conversion of its answer to a string.
+pub type Part = fn(&dyn Any, run: &mut dyn FnMut(&mut dyn FnMut())) -> Option<String>;
+
+/// Type-erased handle to a [`Solution`], as stored in the registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: fn(&str) -> Parsed,
+    pub part_one: Part,
+    pub part_two: Part,
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed {
+    parsed
+        .downcast_ref()
+        .unwrap_or_else(|| panic!("input was not parsed by day {} of {}", S::DAY, S::YEAR))
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            parse: |input| Box::new(S::parse(input)),
+            part_one: |parsed, run| {
+                let mut answer = None;
+                run(&mut || answer = S::part_one(downcast::<S>(parsed)));
+                answer.map(|answer| answer.to_string())
+            },
+            part_two: |parsed, run| {
+                let mut answer = None;
+                run(&mut || answer = S::part_two(downcast::<S>(parsed)));
+                answer.map(|answer| answer.to_string())
+            },
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Part {
+        match part {
+            1 => self.part_one,
+            2 => self.part_two,
+            _ => panic!("part must be 1 or 2, got {}", part),
         }
+    }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+    /// Parses `input` and solves `part` of it.
+    pub fn solve(&self, part: u8, input: &str) -> Option<String> {
+        self.part(part)(&*(self.parse)(input), &mut |solve| solve())
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Environment variable with the year that commands use unless `--year` is passed.
+/// `.cargo/config` sets it for every cargo command.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+pub fn parse_year(s: &str) -> Result<u16, String> {
+    match s.trim().parse() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("\"{}\" is not a year of Advent of Code", s.trim())),
+    }
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", s.trim())),
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Reads `AOC_YEAR`, falling back to its value at build time for binaries run without cargo.
+pub fn default_year() -> u16 {
+    let year = env::var(YEAR_ENV).ok();
+    match year.as_deref().or(option_env!("AOC_YEAR")).map(parse_year) {
+        Some(Ok(year)) => year,
+        Some(Err(e)) => panic!("{} is invalid: {}", YEAR_ENV, e),
+        None => panic!(
+            "{} is not set, add it to the [env] section of .cargo/config",
+            YEAR_ENV
+        ),
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// `src/YYYY`, which holds the solutions, inputs, examples and answers of a year.
+pub fn year_dir(year: u16) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("src").join(year.to_string())
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+pub fn input_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// Why an input or example file could not be read. Every variant names the resolved path.
+#[derive(Debug)]
+pub enum InputError {
+    /// The puzzle input is missing or empty, i.e. it was never downloaded.
+    NotDownloaded {
+        path: PathBuf,
+        year: u16,
+        day: u8,
+    },
+    /// The example file exists but the example was never pasted into it.
+    EmptyExample {
+        path: PathBuf,
+    },
+    Missing {
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotDownloaded { path, year, day } => write!(
+                f,
+                "No input at \"{}\". Run `cargo download {:02} --year {}` to download it.",
+                path.display(),
+                day,
+                year
+            ),
+            InputError::EmptyExample { path } => write!(
+                f,
+                "The example file \"{}\" is empty. Paste the example from the puzzle description into it.",
+                path.display()
+            ),
+            InputError::Missing { path } => write!(f, "\"{}\" does not exist.", path.display()),
+            InputError::Io { path, source } => {
+                write!(f, "Could not read \"{}\": {}", path.display(), source)
             }
         }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads `src/{year}/{folder}/NN.txt`. Missing and empty files are errors, since
+/// `cargo scaffold` creates empty input and example files that still have to be filled in.
+pub fn read_file(year: u16, folder: &str, day: u8) -> Result<String, InputError> {
+    read_path(input_path(year, folder, day), folder, year, day)
+}
+
+fn read_path(path: PathBuf, folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(source) => return Err(InputError::Io { path, source }),
+    };
+
+    if !contents.trim().is_empty() {
+        return Ok(contents);
+    }
+
+    Err(match folder {
+        "inputs" => InputError::NotDownloaded { path, year, day },
+        "examples" if path.exists() => InputError::EmptyExample { path },
+        _ => InputError::Missing { path },
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Writes `contents` to a temporary file next to `path` and renames it, so that `path` never
+/// holds a partial file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = path.with_file_name(format!(".{}.tmp", name));
+
+    fs::write(&tmp, contents)
+        .and_then(|_| fs::rename(&tmp, path))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })
+}
+
+/// Environment variable that overrides the input of `cargo solve`, like `--input`.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Input passed to `cargo solve` with `--input` or `AOC_INPUT`, instead of `src/YYYY/inputs/NN.txt`.
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Path(PathBuf),
+    /// Given as `-`.
+    Stdin,
+}
+
+impl From<&OsStr> for InputSource {
+    fn from(s: &OsStr) -> Self {
+        if s == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(s))
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Path(path) => fs::read_to_string(path).map_err(|source| {
+                if source.kind() == io::ErrorKind::NotFound {
+                    InputError::Missing { path: path.clone() }
+                } else {
+                    InputError::Io {
+                        path: path.clone(),
+                        source,
+                    }
+                }
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| InputError::Io {
+                        path: PathBuf::from("<stdin>"),
+                        source,
+                    })?;
+                Ok(input)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +312,109 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+    fn test_registry() {
+        let days = days::all();
+        assert!(days
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+        assert!(days.iter().all(|day| (1..=25).contains(&day.day)));
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+    #[test]
+    fn test_day_of() {
+        let day = days::get(2022, 1).unwrap();
+        let input = read_file(2022, "examples", 1).unwrap();
+        assert_eq!(day.title, "Calorie Counting");
+        let parsed = (day.parse)(&input);
+        assert_eq!(
+            (day.part_one)(&*parsed, &mut |solve| solve()),
+            Some(String::from("24000"))
+        );
+        assert_eq!(
+            day.part(2)(&*parsed, &mut |solve| solve()),
+            Some(String::from("45000"))
         );
+        assert_eq!(day.solve(2, &input), Some(String::from("45000")));
+    }
+
+    #[test]
+    fn test_read_file_errors() {
+        let dir = env::temp_dir().join(format!("aoc-read-file-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let empty = dir.join("07.txt");
+        fs::write(&empty, "\n").unwrap();
+        let missing = dir.join("08.txt");
+
+        match read_path(missing.clone(), "inputs", 2022, 8) {
+            Err(e @ InputError::NotDownloaded { .. }) => {
+                assert!(e.to_string().contains(&missing.display().to_string()));
+                assert!(e.to_string().contains("cargo download 08 --year 2022"));
+            }
+            other => panic!("expected a missing input, got {:?}", other),
+        }
+        assert!(matches!(
+            read_path(empty.clone(), "inputs", 2022, 7),
+            Err(InputError::NotDownloaded { .. })
+        ));
+        assert!(matches!(
+            read_path(empty, "examples", 2022, 7),
+            Err(InputError::EmptyExample { .. })
+        ));
+        assert!(matches!(
+            read_path(missing, "examples", 2022, 8),
+            Err(InputError::Missing { .. })
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = env::temp_dir().join(format!("aoc-write-atomic-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("01.txt");
+        fs::write(&path, "old input that is longer").unwrap();
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        write_atomic(&path, "new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        assert!(write_atomic(&dir.join("missing").join("01.txt"), "new").is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse_year("2022"), Ok(2022));
+        assert!(parse_year("2014").is_err());
+        assert!(parse_year("22x").is_err());
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("1"), Ok(1));
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("26").is_err());
+        assert!(parse_day("1x").is_err());
+    }
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!(InputSource::from(OsStr::new("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from(OsStr::new("stress.txt")),
+            InputSource::Path(PathBuf::from("stress.txt"))
         );
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        let input = InputSource::from(input_path(2022, "examples", 1).as_os_str());
+        assert_eq!(
+            input.read().unwrap(),
+            read_file(2022, "examples", 1).unwrap()
         );
+        assert!(matches!(
+            InputSource::from(OsStr::new("does/not/exist.txt")).read(),
+            Err(InputError::Missing { .. })
+        ));
     }
 }
@@ -2,45 +2,430 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use aoc::answers::{self, Answers, Verdict};
+use aoc::bench::{self, BenchOptions};
+use aoc::client::{self, Client};
+use aoc::filter::Filter;
+use aoc::history::{self, Run};
+use aoc::report::{self, Format};
+use aoc::runner::{self, DayResult, Status};
+use aoc::{Day, InputError};
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+enum Command {
+    All(AllOptions),
+    Time {
+        year: u16,
+        day: Option<u8>,
+        options: BenchOptions,
+    },
+    /// Checks that the session cookie is accepted and prints whose it is.
+    AuthCheck {
+        year: u16,
+    },
+}
+
+struct AllOptions {
+    year: u16,
+    format: Format,
+    readme: bool,
+    /// Number of days solved at the same time.
+    jobs: usize,
+    /// Re-time every part on its own after a parallel run.
+    sequential_timing: bool,
+    /// Time after which a part is reported as timed out.
+    timeout: Option<Duration>,
+    filter: Filter,
+    history: HistoryOptions,
+}
+
+struct HistoryOptions {
+    /// Compare against the latest run saved under this name instead of the previous run.
+    baseline: Option<String>,
+    /// Save this run under a name so later runs can compare against it.
+    save_baseline: Option<String>,
+    /// Slowdown in percent above which a part counts as a regression.
+    threshold: f64,
+}
+
+fn parse_jobs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err(String::from("need at least one job")),
+        Ok(jobs) => Ok(jobs),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+fn parse_args() -> Result<Command, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let subcommand = args.subcommand()?;
+    let year = args
+        .opt_value_from_fn(["-y", "--year"], aoc::parse_year)?
+        .unwrap_or_else(aoc::default_year);
+
+    let command = match subcommand.as_deref() {
+        None => Command::All(AllOptions {
+            year,
+            format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+            readme: args.contains("--readme"),
+            jobs: args
+                .opt_value_from_fn(["-j", "--jobs"], parse_jobs)?
+                .unwrap_or(1),
+            sequential_timing: args.contains("--sequential-timing"),
+            timeout: args
+                .opt_value_from_fn("--timeout", runner::parse_timeout)?
+                .unwrap_or(Some(runner::DEFAULT_TIMEOUT)),
+            filter: Filter::from_args(&mut args)?,
+            history: HistoryOptions {
+                baseline: args.opt_value_from_str("--baseline")?,
+                save_baseline: args.opt_value_from_str("--save-baseline")?,
+                threshold: args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(history::DEFAULT_THRESHOLD),
+            },
+        }),
+        Some("time") => {
+            let defaults = BenchOptions::default();
+            let budget: Option<u64> = args.opt_value_from_str("--budget")?;
+            Command::Time {
+                year,
+                options: BenchOptions {
+                    warmup: args
+                        .opt_value_from_str("--warmup")?
+                        .unwrap_or(defaults.warmup),
+                    iterations: args.opt_value_from_str(["-n", "--iterations"])?,
+                    budget: budget.map(Duration::from_millis).unwrap_or(defaults.budget),
+                },
+                day: args.opt_free_from_str()?,
+            }
+        }
+        Some("auth") => match args.subcommand()?.as_deref() {
+            Some("check") => Command::AuthCheck { year },
+            _ => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: String::from("expected `auth check`"),
+                })
+            }
+        },
+        Some(cmd) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unknown command \"{}\"", cmd),
+            })
+        }
+    };
+
+    runner::reject_remaining(args)?;
+    Ok(command)
+}
+
+/// Reads the input of `day`. Days whose input wasn't downloaded yet are reported as
+/// not solved, any other error is printed as a warning.
+fn read_input(day: &Day) -> Option<String> {
+    match aoc::read_file(day.year, "inputs", day.day) {
+        Ok(input) => Some(input),
+        Err(InputError::NotDownloaded { .. }) => None,
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    }
+}
+
+/// Re-runs every solved part on its own so that its timing isn't skewed by other days
+/// running at the same time.
+fn retime(days: &[Day], results: &mut [DayResult]) {
+    for result in results.iter_mut() {
+        let day = match days.iter().find(|day| day.day == result.day) {
+            Some(day) => day,
+            None => continue,
+        };
+        // a parse step that timed out would hang here, as there's no timeout.
+        let parse = match result.parse.as_mut().filter(|parse| !parse.timed_out) {
+            Some(parse) => parse,
+            None => continue,
+        };
+        if let Some(input) = read_input(day) {
+            let (parsed, timing) = runner::run_parse(day, &input);
+            parse.elapsed = timing.elapsed;
+
+            for part in result.parts.iter_mut() {
+                if part.status == Status::Solved {
+                    part.elapsed = runner::run_part(day, part.part, &*parsed).elapsed;
                 }
+            }
+        }
+    }
+}
+
+fn run_all(options: AllOptions) {
+    let AllOptions {
+        year,
+        format,
+        readme,
+        jobs,
+        sequential_timing,
+        timeout,
+        filter,
+        history,
+    } = options;
+
+    let answers = match Answers::load(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!(
+                "Failed to read \"{}\": {}",
+                answers::answers_path(year).display(),
+                e
             );
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                aoc::parse_exec_time(&output)
-            }
-        })
-        .sum();
+    let parallel = jobs > 1;
+    let comparable_timings = !parallel || sequential_timing;
+    // with --sequential-timing, results are printed after they have been re-timed.
+    let stream = format == Format::Text && !(parallel && sequential_timing);
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    if !comparable_timings && readme {
+        eprintln!(
+            "Timings of parallel runs are not comparable, add --sequential-timing to use --readme."
+        );
+        process::exit(1);
+    }
+
+    if filter.is_partial() && readme {
+        eprintln!(
+            "--readme needs a run of all days and parts, remove --days and --part to use it."
+        );
+        process::exit(1);
+    }
+
+    let days: Vec<Day> = aoc::days::of_year(year)
+        .into_iter()
+        .filter(|day| filter.includes_day(day.day))
+        .collect();
+    if days.is_empty() {
+        eprintln!(
+            "No day of {} in the registry (src/days.rs) matches --days.",
+            year
+        );
+        process::exit(1);
+    }
+
+    let parts = filter.parts();
+    let mut results: Vec<DayResult> = Vec::new();
+
+    runner::run_ordered(
+        &days,
+        jobs,
+        |day| match read_input(day) {
+            Some(input) => {
+                let mut result = runner::run_day(day, &input, &parts, timeout);
+                answers.check(&mut result);
+                result
+            }
+            None => DayResult::without_input(day, &parts),
+        },
+        |mut result| {
+            if !filter.retain(&mut result) {
+                return;
+            }
+            if stream {
+                runner::print_day(&result);
+            }
+            results.push(result);
+        },
     );
+
+    if parallel && sequential_timing {
+        retime(&days, &mut results);
+
+        if format == Format::Text {
+            results.iter().for_each(runner::print_day);
+        }
+    }
+
+    match format {
+        Format::Text => {
+            let total: Duration = results.iter().map(DayResult::elapsed).sum();
+            runner::print_total(total);
+        }
+        Format::Json => print!("{}", report::json(&results)),
+        Format::Csv => print!("{}", report::csv(&results)),
+    }
+
+    // a part that timed out keeps running in the background and slows down every later day.
+    let timed_out = results.iter().any(DayResult::timed_out);
+
+    if readme && timed_out {
+        eprintln!(
+            "Skipped updating benchmarks in \"README.md\": timings after a timeout are skewed."
+        );
+    } else if readme {
+        match aoc::readme::update("README.md", year, &results) {
+            Ok(()) => eprintln!("🎄 Updated benchmarks in \"README.md\"."),
+            Err(e) => {
+                eprintln!("Failed to update benchmarks in \"README.md\": {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // like --readme, the history only compares runs of all days and parts.
+    let regressed = if timed_out {
+        eprintln!("Skipped benchmark history: timings after a timeout are skewed.");
+        false
+    } else if !comparable_timings {
+        eprintln!("Skipped benchmark history: timings of parallel runs are not comparable. Add --sequential-timing to record them.");
+        false
+    } else if filter.is_partial() {
+        eprintln!("Skipped benchmark history: only runs of all days and parts are recorded. Remove --days and --part to record them.");
+        false
+    } else {
+        !record_history(year, &results, format, history)
+    };
+    let failed = results
+        .iter()
+        .flat_map(|result| result.parts.iter())
+        .any(|part| matches!(part.verdict, Some(Verdict::Fail { .. })));
+
+    if regressed || failed || timed_out {
+        process::exit(1);
+    }
+}
+
+/// Appends this run to the benchmark history and prints deltas against the reference run.
+/// Returns `false` if any part regressed beyond the threshold.
+fn record_history(
+    year: u16,
+    results: &[DayResult],
+    format: Format,
+    options: HistoryOptions,
+) -> bool {
+    let path = history::history_path(year);
+    let run = Run::from_results(results, options.save_baseline);
+
+    let runs = match history::load(&path) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!(
+                "Failed to read benchmark history \"{}\": {}",
+                path.display(),
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    if let Err(e) = history::append(&path, &run) {
+        eprintln!(
+            "Failed to write benchmark history \"{}\": {}",
+            path.display(),
+            e
+        );
+    }
+
+    let reference = match history::find_reference(&runs, &run.profile, options.baseline.as_deref())
+    {
+        Some(reference) => reference,
+        None => {
+            if let Some(baseline) = options.baseline {
+                eprintln!("No {} run saved as baseline \"{}\".", run.profile, baseline);
+            }
+            return true;
+        }
+    };
+
+    let deltas = history::compare(reference, &run);
+    if deltas.is_empty() {
+        return true;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let comparison = history::format_comparison(reference, &deltas, options.threshold, now);
+
+    // keep machine-readable output on stdout parseable.
+    if format == Format::Text {
+        println!("----------");
+        println!("{}", comparison);
+    } else {
+        eprintln!("{}", comparison);
+    }
+
+    !deltas
+        .iter()
+        .any(|delta| delta.is_regression(options.threshold))
+}
+
+fn time(days: Vec<Day>, options: &BenchOptions) {
+    for day in days {
+        runner::print_day_header(day.day);
+
+        match read_input(&day) {
+            Some(input) => {
+                let (parsed, stats) = bench::bench_parse(&day, &input, options);
+                bench::print_bench_parse(&stats);
+
+                for part in 1..=2 {
+                    bench::print_bench(&bench::bench_part(&day, part, &*parsed, options));
+                }
+            }
+            None => println!("Not solved (no input file)."),
+        }
+    }
+}
+
+fn auth_check(year: u16) {
+    let session = match client::session() {
+        Some(session) => session,
+        None => {
+            eprintln!("{}", client::Error::NoSession);
+            process::exit(1);
+        }
+    };
+    println!("Using the session cookie from {}.", session.source);
+
+    match Client::from_env().and_then(|client| client.user(year)) {
+        Ok(user) => println!("🎄 The session cookie is valid and belongs to {}.", user),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let command = match parse_args() {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match command {
+        Command::All(options) => run_all(options),
+        Command::Time { year, day, options } => {
+            let days = match day {
+                Some(day) => match aoc::days::get(year, day) {
+                    Some(day) => vec![day],
+                    None => {
+                        eprintln!(
+                            "Day {:02} of {} is not in the registry (src/days.rs).",
+                            day, year
+                        );
+                        process::exit(1);
+                    }
+                },
+                None => aoc::days::of_year(year),
+            };
+
+            time(days, &options);
+        }
+        Command::AuthCheck { year } => auth_check(year),
+    }
 }
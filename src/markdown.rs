@@ -0,0 +1,490 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Converts the puzzle descriptions on Advent of Code pages to Markdown. Only the markup used
+//! in `<article class="day-desc">` is supported: headings, paragraphs, code blocks, inline
+//! code, emphasis, lists and links.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Token<'a> {
+    Start { name: String, attrs: &'a str },
+    End { name: String },
+    Text(String),
+}
+
+/// Replaces the entities that Advent of Code uses, including numeric ones.
+pub(crate) fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let c = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some("nbsp") => Some(' '),
+            Some(entity) if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                .ok()
+                .and_then(char::from_u32),
+            Some(entity) if entity.starts_with('#') => {
+                entity[1..].parse().ok().and_then(char::from_u32)
+            }
+            _ => None,
+        };
+
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Splits `html` into tags and decoded text. Comments are dropped.
+pub(crate) fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if rest.starts_with('<') {
+            if let Some(end) = rest.find('>') {
+                let tag = rest[1..end].trim_end_matches('/');
+                rest = &rest[end + 1..];
+
+                let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                match name.strip_prefix('/') {
+                    Some(name) => tokens.push(Token::End {
+                        name: name.to_ascii_lowercase(),
+                    }),
+                    None => tokens.push(Token::Start {
+                        name: name.to_ascii_lowercase(),
+                        attrs,
+                    }),
+                }
+                continue;
+            }
+        }
+
+        // text runs up to the next tag, but at least one char so a lone `<` makes progress.
+        let first = rest.chars().next().map_or(0, char::len_utf8);
+        let end = rest[first..]
+            .find('<')
+            .map_or(rest.len(), |end| end + first);
+        tokens.push(Token::Text(decode_entities(&rest[..end])));
+        rest = &rest[end..];
+    }
+
+    tokens
+}
+
+/// Value of the attribute `name` in the attributes of a start tag.
+pub(crate) fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attrs;
+    while let Some(start) = rest.find(name) {
+        let after = &rest[start + name.len()..];
+        let standalone = rest[..start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+
+        if let (true, Some(value)) = (standalone, after.trim_start().strip_prefix('=')) {
+            let value = value.trim_start();
+            let quote = value.chars().next()?;
+            return if quote == '"' || quote == '\'' {
+                value[1..].find(quote).map(|end| &value[1..end + 1])
+            } else {
+                Some(value.split_whitespace().next().unwrap_or_default())
+            };
+        }
+        rest = after;
+    }
+    None
+}
+
+/// The inner HTML of every `<article class="day-desc">`, i.e. one per unlocked part.
+pub(crate) fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let open_end = match rest[start..].find('>') {
+            Some(end) => start + end + 1,
+            None => break,
+        };
+        let close = rest[open_end..]
+            .find("</article>")
+            .map_or(rest.len(), |end| open_end + end);
+
+        if attr(&rest[start + "<article".len()..open_end - 1], "class")
+            .is_some_and(|class| class.split_whitespace().any(|c| c == "day-desc"))
+        {
+            articles.push(&rest[open_end..close]);
+        }
+        rest = &rest[close..];
+        rest = rest.strip_prefix("</article>").unwrap_or(rest);
+    }
+
+    articles
+}
+
+/// `src/YYYY/puzzles/NN.md`, where `cargo read` saves the description of a day.
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+/// Converts the puzzle page `html` to Markdown and writes it to `path`, creating its folder.
+pub fn save_puzzle(path: &Path, html: &str, page_url: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    crate::write_atomic(path, &puzzle_to_markdown(html, page_url))
+}
+
+/// Number of parts in a puzzle page, which is 2 once part one is solved.
+pub fn count_parts(html: &str) -> usize {
+    articles(html).len()
+}
+
+/// Resolves links against the page at `page_url`, e.g. `https://adventofcode.com/2022/day/1`.
+fn resolve(href: &str, page_url: &str) -> String {
+    if href.contains("://") || href.starts_with('#') || href.starts_with("mailto:") {
+        return String::from(href);
+    }
+
+    if let Some(path) = href.strip_prefix('/') {
+        let origin_end = page_url
+            .find("://")
+            .and_then(|scheme| page_url[scheme + 3..].find('/').map(|end| scheme + 3 + end))
+            .unwrap_or(page_url.len());
+        return format!("{}/{}", &page_url[..origin_end], path);
+    }
+
+    let dir_end = page_url.rfind('/').map_or(page_url.len(), |end| end + 1);
+    format!("{}{}", &page_url[..dir_end], href)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Wraps `code` in enough backticks that it can contain backticks itself.
+fn inline_code(code: &str) -> String {
+    let mut fence = String::from("`");
+    while code.contains(&fence) {
+        fence.push('`');
+    }
+    let padding = if fence.len() > 1 || code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{}{}{}{}{}", fence, padding, code, padding, fence)
+}
+
+/// Renders inline content of a block. Whitespace is collapsed like a browser would.
+#[derive(Default)]
+struct Inline {
+    text: String,
+    /// Targets of the open links.
+    links: Vec<String>,
+    /// Set inside `<code>`, collecting the raw code and whether it is emphasized.
+    code: Option<(String, bool)>,
+}
+
+impl Inline {
+    fn push_text(&mut self, text: &str) {
+        if let Some((code, _)) = self.code.as_mut() {
+            code.push_str(text);
+            return;
+        }
+
+        let mut words = text.split_whitespace().peekable();
+        if text.starts_with(char::is_whitespace) && !self.text.ends_with(' ') {
+            self.text.push(' ');
+        }
+        while let Some(word) = words.next() {
+            self.text.push_str(&escape(word));
+            if words.peek().is_some() {
+                self.text.push(' ');
+            }
+        }
+        if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+            self.text.push(' ');
+        }
+    }
+
+    fn start(&mut self, name: &str, attrs: &str, page_url: &str) {
+        match (name, self.code.as_mut()) {
+            ("em", Some((_, emphasized))) => *emphasized = true,
+            ("code", None) => self.code = Some((String::new(), false)),
+            ("em", None) => self.text.push('*'),
+            ("a", None) => {
+                let href = attr(attrs, "href").map(decode_entities).unwrap_or_default();
+                self.text.push('[');
+                self.links.push(resolve(&href, page_url));
+            }
+            ("br", None) => self.text.push_str("  \n"),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        match name {
+            "code" => {
+                if let Some((code, emphasized)) = self.code.take() {
+                    let code = inline_code(&code);
+                    if emphasized {
+                        self.text.push_str(&format!("*{}*", code));
+                    } else {
+                        self.text.push_str(&code);
+                    }
+                }
+            }
+            "em" if self.code.is_none() => self.text.push('*'),
+            "a" if self.code.is_none() => {
+                if let Some(href) = self.links.pop() {
+                    self.text.push_str(&format!("]({})", href));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(self) -> String {
+        String::from(self.text.trim())
+    }
+}
+
+/// Converts the puzzle description of a full puzzle page to Markdown. `page_url` is used to
+/// resolve relative links.
+pub fn puzzle_to_markdown(html: &str, page_url: &str) -> String {
+    let markdown: String = articles(html)
+        .iter()
+        .map(|article| article_to_markdown(article, page_url))
+        .collect();
+    format!("{}\n", markdown.trim_end())
+}
+
+fn article_to_markdown(html: &str, page_url: &str) -> String {
+    let mut markdown = String::new();
+    let mut inline = Inline::default();
+    let mut block: Option<&str> = None;
+    let mut list_depth = 0;
+    let mut pre: Option<String> = None;
+
+    for token in tokens(html) {
+        if let Some(code) = pre.as_mut() {
+            match token {
+                Token::Text(text) => code.push_str(&text),
+                Token::End { name } if name == "pre" => {
+                    let code = pre.take().unwrap_or_default();
+                    let mut fence = String::from("```");
+                    while code.contains(&fence) {
+                        fence.push('`');
+                    }
+                    markdown.push_str(&format!(
+                        "{}\n{}{}{}\n\n",
+                        fence,
+                        code,
+                        if code.ends_with('\n') { "" } else { "\n" },
+                        fence
+                    ));
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        match token {
+            Token::Start { name, attrs } => match name.as_str() {
+                "pre" => pre = Some(String::new()),
+                "h2" | "p" => block = Some(if name == "h2" { "## " } else { "" }),
+                "ul" => {
+                    // a nested list ends the text of the item that contains it.
+                    if block == Some("- ") {
+                        let text = std::mem::take(&mut inline).finish();
+                        let indent = "  ".repeat(list_depth.max(1) - 1);
+                        markdown.push_str(&format!("{}- {}\n", indent, text));
+                        block = None;
+                    }
+                    list_depth += 1;
+                }
+                "li" => block = Some("- "),
+                _ => inline.start(&name, attrs, page_url),
+            },
+            Token::End { name } => match name.as_str() {
+                "li" => {
+                    let text = std::mem::take(&mut inline).finish();
+                    let indent = "  ".repeat(list_depth.max(1) - 1);
+                    if block.take().is_some() {
+                        markdown.push_str(&format!("{}- {}\n", indent, text));
+                    }
+                }
+                "h2" | "p" => {
+                    let text = std::mem::take(&mut inline).finish();
+                    let prefix = block.take().unwrap_or_default();
+                    if !text.is_empty() {
+                        markdown.push_str(&format!("{}{}\n\n", prefix, text));
+                    }
+                }
+                "ul" => {
+                    list_depth -= 1;
+                    if list_depth == 0 {
+                        markdown.push('\n');
+                    }
+                }
+                _ => inline.end(&name),
+            },
+            Token::Text(text) => inline.push_text(&text),
+        }
+    }
+
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_URL: &str = "https://adventofcode.com/2022/day/1";
+
+    #[test]
+    fn test_fixture() {
+        let html = include_str!("../tests/fixtures/puzzle.html");
+        assert_eq!(count_parts(html), 2);
+        assert_eq!(
+            puzzle_to_markdown(html, PAGE_URL),
+            include_str!("../tests/fixtures/puzzle.md")
+        );
+    }
+
+    #[test]
+    fn test_fixture_part_one() {
+        let html = include_str!("../tests/fixtures/puzzle_part_one.html");
+        assert_eq!(count_parts(html), 1);
+        assert_eq!(
+            puzzle_to_markdown(html, PAGE_URL),
+            include_str!("../tests/fixtures/puzzle_part_one.md")
+        );
+    }
+
+    #[test]
+    fn test_save_puzzle() {
+        let dir = std::env::temp_dir().join(format!("aoc-markdown-{}", std::process::id()));
+        let path = dir.join("puzzles").join("01.md");
+
+        let html = include_str!("../tests/fixtures/puzzle_part_one.html");
+        save_puzzle(&path, html, PAGE_URL).unwrap();
+        let html = include_str!("../tests/fixtures/puzzle.html");
+        save_puzzle(&path, html, PAGE_URL).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            include_str!("../tests/fixtures/puzzle.md")
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;&#39;&#x41;&quot; &unknown; &"),
+            "a <b> &'A\" &unknown; &"
+        );
+    }
+
+    #[test]
+    fn test_inline() {
+        let markdown = |html| {
+            puzzle_to_markdown(
+                &format!("<article class=\"day-desc\">{}</article>", html),
+                PAGE_URL,
+            )
+        };
+
+        assert_eq!(
+            markdown("<p>Use <code>a*b</code>, <em>not</em> <code><em>x</em></code> or 2*3.</p>"),
+            "Use `a*b`, *not* *`x`* or 2\\*3.\n"
+        );
+        assert_eq!(
+            markdown("<p>See <a href=\"/2022/about\">about</a>, <a href=\"1/input\" target=\"_blank\">input</a> and <a href=\"#part2\">two</a>.</p>"),
+            "See [about](https://adventofcode.com/2022/about), [input](https://adventofcode.com/2022/day/1/input) and [two](#part2).\n"
+        );
+        assert_eq!(
+            markdown("<p>A <code>`tick`</code>.</p>"),
+            "A `` `tick` ``.\n"
+        );
+        assert_eq!(
+            markdown("<ul>\n<li>a<ul><li>b</li></ul></li>\n<li>c</li>\n</ul><p>d</p>"),
+            "- a\n  - b\n- c\n\nd\n"
+        );
+        assert_eq!(
+            markdown("<pre><code>```\n&lt;&gt;</code></pre>"),
+            "````\n```\n<>\n````\n"
+        );
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(
+            tokens("<p>é</p>"),
+            vec![
+                Token::Start {
+                    name: String::from("p"),
+                    attrs: ""
+                },
+                Token::Text(String::from("é")),
+                Token::End {
+                    name: String::from("p")
+                },
+            ]
+        );
+        assert_eq!(
+            puzzle_to_markdown(
+                "<article class=\"day-desc\"><p>Élan, <em>ünïcode</em> → ok</p></article>",
+                PAGE_URL
+            ),
+            "Élan, *ünïcode* → ok\n"
+        );
+    }
+
+    #[test]
+    fn test_attr() {
+        assert_eq!(attr(" class=\"day-desc\"", "class"), Some("day-desc"));
+        assert_eq!(attr(" data-class='x' class=y", "class"), Some("y"));
+        assert_eq!(attr(" href=\"/\"", "class"), None);
+    }
+}
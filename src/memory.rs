@@ -0,0 +1,193 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Allocation statistics for solutions. Counting is done by a global allocator that is only
+//! installed with the `alloc-stats` feature, so regular builds don't pay for it.
+
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    /// Total bytes requested, including memory that was freed again.
+    pub bytes: u64,
+    /// Number of allocations, counting every reallocation.
+    pub count: u64,
+    /// Highest amount of live heap memory, relative to the start of the measurement.
+    pub peak: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "allocated: {} in {} allocations, peak: {}",
+            format_bytes(self.bytes),
+            self.count,
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{}B", bytes)
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+/// Whether allocations are counted, i.e. the `alloc-stats` feature is enabled.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Runs `f` and returns the allocations it made on the current thread, or `None` if
+/// allocations aren't counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        counting::reset();
+        let value = f();
+        (value, Some(counting::stats()))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    (f(), None)
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Counters are per thread so that days solved in parallel don't mix their numbers.
+    #[derive(Clone, Copy)]
+    struct Counters {
+        bytes: u64,
+        count: u64,
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters { bytes: 0, count: 0, live: 0, peak: 0 })
+        };
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        // `try_with` fails while the thread is being torn down, those allocations are ignored.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut c = counters.get();
+            if allocated > 0 {
+                c.bytes += allocated as u64;
+                c.count += 1;
+            }
+            c.live += allocated as i64 - freed as i64;
+            c.peak = c.peak.max(c.live);
+            counters.set(c);
+        });
+    }
+
+    pub fn reset() {
+        COUNTERS.with(|counters| {
+            counters.set(Counters {
+                bytes: 0,
+                count: 0,
+                live: 0,
+                peak: 0,
+            })
+        });
+    }
+
+    pub fn stats() -> AllocStats {
+        let c = COUNTERS.with(Cell::get);
+        AllocStats {
+            bytes: c.bytes,
+            count: c.count,
+            peak: c.peak as u64,
+        }
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(0, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let stats = AllocStats {
+            bytes: 3 * 1024 * 1024 / 2,
+            count: 12,
+            peak: 512,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "allocated: 1.5MiB in 12 allocations, peak: 512B"
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let v: Vec<u64> = Vec::with_capacity(1024);
+            drop(v);
+            let v: Vec<u64> = Vec::with_capacity(16);
+            v.capacity()
+        });
+        assert_eq!(len, 16);
+        assert_eq!(stats.is_some(), ENABLED);
+
+        if let Some(stats) = stats {
+            assert_eq!(stats.count, 2);
+            assert_eq!(stats.bytes, (1024 + 16) * 8);
+            assert_eq!(stats.peak, 1024 * 8);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Extracts the title, the example input and its expected answers from a puzzle page, so that
+//! `cargo scaffold` can start a day with real tests.
+
+use crate::markdown::{articles, tokens, Token};
+
+/// The title of the puzzle, from its heading `--- Day 1: Calorie Counting ---`.
+pub fn title(html: &str) -> Option<String> {
+    let article = *articles(html).first()?;
+    let mut in_heading = false;
+    let mut heading = String::new();
+
+    for token in tokens(article) {
+        match token {
+            Token::Start { name, .. } if name == "h2" => in_heading = true,
+            Token::Text(text) if in_heading => heading.push_str(&text),
+            Token::End { name } if name == "h2" => break,
+            _ => {}
+        }
+    }
+
+    let heading = heading.trim().trim_matches('-').trim();
+    let title = heading.split_once(": ").map_or(heading, |(_, title)| title);
+    (!title.is_empty()).then(|| String::from(title))
+}
+
+/// The text of the first `<pre><code>` block, which is the example input of nearly every puzzle.
+pub fn example(html: &str) -> Option<String> {
+    let article = *articles(html).first()?;
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut example = String::new();
+
+    for token in tokens(article) {
+        match token {
+            Token::Start { name, .. } if name == "pre" => in_pre = true,
+            Token::Start { name, .. } if name == "code" && in_pre => in_code = true,
+            Token::Text(text) if in_code => example.push_str(&text),
+            Token::End { name } if name == "pre" && in_code => break,
+            _ => {}
+        }
+    }
+
+    if example.trim().is_empty() {
+        None
+    } else {
+        Some(example)
+    }
+}
+
+/// The expected answer of every unlocked part: the last highlighted code (`<code><em>` or
+/// `<em><code>`) of its description.
+pub fn answers(html: &str) -> Vec<Option<String>> {
+    articles(html)
+        .into_iter()
+        .map(|article| highlighted_code(article).pop())
+        .collect()
+}
+
+fn highlighted_code(article: &str) -> Vec<String> {
+    let mut highlighted = Vec::new();
+    let mut current = String::new();
+    let (mut code, mut em) = (0, 0);
+
+    for token in tokens(article) {
+        match token {
+            Token::Start { name, .. } if name == "code" => code += 1,
+            Token::Start { name, .. } if name == "em" => em += 1,
+            Token::End { name } if name == "code" || name == "em" => {
+                if name == "code" {
+                    code -= 1;
+                } else {
+                    em -= 1;
+                }
+                let answer = current.trim();
+                if !answer.is_empty() {
+                    highlighted.push(String::from(answer));
+                }
+                current.clear();
+            }
+            Token::Text(text) if code > 0 && em > 0 => current.push_str(&text),
+            _ => {}
+        }
+    }
+
+    highlighted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn fixture(name: &str) -> String {
+        fs::read_to_string(format!("tests/fixtures/{}", name)).unwrap()
+    }
+
+    #[test]
+    fn test_fixture() {
+        let html = fixture("puzzle.html");
+        assert_eq!(title(&html).as_deref(), Some("Sum of Groups"));
+        assert_eq!(example(&html).as_deref(), Some("1\n2\n\n3\n\n4\n5\n"));
+        assert_eq!(
+            answers(&html),
+            vec![Some(String::from("9")), Some(String::from("12"))]
+        );
+    }
+
+    #[test]
+    fn test_fixture_part_one() {
+        let html = fixture("puzzle_part_one.html");
+        assert!(example(&html).is_some());
+        assert_eq!(answers(&html), vec![Some(String::from("9"))]);
+    }
+
+    #[test]
+    fn test_highlighted() {
+        let html = "<article class=\"day-desc\"><pre><code>a<em>b</em>\n</code></pre>\
+            <p><code><em>1</em></code> and <em>not code</em>, then <code>CMZ</code>.</p></article>\
+            <article class=\"day-desc\"><p>No answer <em>here</em>.</p></article>";
+        assert_eq!(example(html), Some(String::from("ab\n")));
+        assert_eq!(answers(html), vec![Some(String::from("1")), None]);
+        assert_eq!(example("<p>no article</p>"), None);
+        assert_eq!(title(html), None);
+        assert_eq!(
+            title("<article class=\"day-desc\"><h2>--- Day 5: Supply Stacks ---</h2></article>")
+                .as_deref(),
+            Some("Supply Stacks")
+        );
+    }
+}
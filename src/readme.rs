@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{DayResult, Status};
+use std::path::Path;
+use std::time::Duration;
+use std::{fmt, fs, io};
+
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    MissingMarkers,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::MissingMarkers => write!(
+                f,
+                "could not find two \"{}\" markers to place the table between",
+                MARKER
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+fn format_parse(result: &DayResult) -> String {
+    match &result.parse {
+        Some(parse) if !parse.timed_out => format!("`{:.1?}`", parse.elapsed),
+        _ => String::from("-"),
+    }
+}
+
+fn format_part(result: &DayResult, part: u8) -> String {
+    match result.parts.iter().find(|p| p.part == part) {
+        Some(p) if p.status == Status::Solved => format!("`{:.1?}`", p.elapsed),
+        _ => String::from("-"),
+    }
+}
+
+/// Benchmark table of `results`, which are days of `year`.
+pub fn table(year: u16, results: &[DayResult]) -> String {
+    let mut table = String::from("## Benchmarks\n\n");
+    table.push_str("| Day | Parse | Part 1 | Part 2 |\n");
+    table.push_str("| :---: | :---: | :---: | :---: |\n");
+
+    for result in results {
+        table.push_str(&format!(
+            "| [Day {}](./src/{}/{:02}.rs) | {} | {} | {} |\n",
+            result.day,
+            year,
+            result.day,
+            format_parse(result),
+            format_part(result, 1),
+            format_part(result, 2)
+        ));
+    }
+
+    let total: Duration = results.iter().map(DayResult::elapsed).sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+    table
+}
+
+/// Replaces everything between the two benchmarking markers in `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, Error> {
+    let start = readme.find(MARKER).ok_or(Error::MissingMarkers)? + MARKER.len();
+    let end = readme[start..].find(MARKER).ok_or(Error::MissingMarkers)? + start;
+
+    Ok(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+/// Rewrites the table of the readme at `path` atomically, so an interrupted run can't truncate it.
+pub fn update(path: &str, year: u16, results: &[DayResult]) -> Result<(), Error> {
+    let readme = fs::read_to_string(path)?;
+    crate::write_atomic(
+        Path::new(path),
+        &replace_table(&readme, &table(year, results))?,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{ParseResult, PartResult};
+
+    fn results() -> Vec<DayResult> {
+        let part = |part, status, micros| PartResult {
+            part,
+            answer: None,
+            status,
+            verdict: None,
+            elapsed: Duration::from_micros(micros),
+            memory: None,
+        };
+
+        vec![
+            DayResult {
+                day: 1,
+                title: "Calorie Counting",
+                parse: Some(ParseResult {
+                    elapsed: Duration::from_micros(40),
+                    memory: None,
+                    timed_out: false,
+                }),
+                parts: vec![part(1, Status::Solved, 26), part(2, Status::Solved, 1500)],
+            },
+            DayResult {
+                day: 2,
+                title: "Rock Paper Scissors",
+                parse: None,
+                parts: vec![part(1, Status::Solved, 10), part(2, Status::Unsolved, 0)],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            table(2022, &results()),
+            "## Benchmarks\n\n\
+             | Day | Parse | Part 1 | Part 2 |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/2022/01.rs) | `40.0µs` | `26.0µs` | `1.5ms` |\n\
+             | [Day 2](./src/2022/02.rs) | - | `10.0µs` | - |\n\
+             \n**Total: 1.58ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# AoC\n{}\nold table\n{}\n## Usage\n", MARKER, MARKER);
+        assert_eq!(
+            replace_table(&readme, "new table\n").unwrap(),
+            format!("# AoC\n{}\nnew table\n{}\n## Usage\n", MARKER, MARKER)
+        );
+
+        assert!(matches!(
+            replace_table("# AoC\n", "new table\n"),
+            Err(Error::MissingMarkers)
+        ));
+    }
+}
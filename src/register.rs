@@ -0,0 +1,408 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Registers a scaffolded day in the files that list every day: the module list of its year,
+//! the registry in `src/days.rs`, the stars table of the readme and the VS Code debug
+//! configurations. Every edit is idempotent, so scaffolding a day twice changes nothing.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    /// The file has to be rewritten with `contents`, which adds the `added` lines.
+    Update {
+        contents: String,
+        added: Vec<String>,
+    },
+    /// The day is registered already.
+    Unchanged,
+    /// The file can't be updated, for the given reason.
+    Skipped(String),
+}
+
+pub struct Target {
+    pub path: PathBuf,
+    pub change: Change,
+}
+
+/// Plans the edits that register `day` of `year`, without writing anything.
+pub fn plan(year: u16, day: u8) -> Vec<Target> {
+    let year_module_path = PathBuf::from(format!("src/{}/mod.rs", year));
+    let registry_path = PathBuf::from("src/days.rs");
+    let readme_path = PathBuf::from("README.md");
+    let launch_path = PathBuf::from(".vscode/launch.json");
+
+    let read = |path: &Path| match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Change::Skipped(format!("could not read it: {}", e))),
+    };
+    let missing = || Change::Skipped(String::from("the file does not exist"));
+
+    vec![
+        Target {
+            change: match read(&year_module_path) {
+                Ok(contents) => year_module(contents.as_deref(), year, day),
+                Err(e) => e,
+            },
+            path: year_module_path,
+        },
+        Target {
+            change: match read(&registry_path) {
+                Ok(Some(contents)) => registry(&contents, year, day),
+                Ok(None) => missing(),
+                Err(e) => e,
+            },
+            path: registry_path,
+        },
+        Target {
+            change: match read(&readme_path) {
+                Ok(Some(contents)) => readme(&contents, year, day),
+                Ok(None) => missing(),
+                Err(e) => e,
+            },
+            path: readme_path,
+        },
+        Target {
+            change: match read(&launch_path) {
+                Ok(Some(contents)) => launch(&contents, year, day),
+                Ok(None) => missing(),
+                Err(e) => e,
+            },
+            path: launch_path,
+        },
+    ]
+}
+
+/// Inserts `lines` before the first line for which `after` is true, or after the last line
+/// for which `within` is true if there is none. Returns `None` if no line is `within`.
+fn insert_sorted(
+    contents: &str,
+    lines: &[String],
+    within: impl Fn(&str) -> bool,
+    after: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let existing: Vec<&str> = contents.lines().collect();
+    let index = match existing.iter().position(|line| after(line)) {
+        Some(index) => index,
+        None => existing.iter().rposition(|line| within(line))? + 1,
+    };
+
+    let mut updated: Vec<&str> = existing[..index].to_vec();
+    updated.extend(lines.iter().map(String::as_str));
+    updated.extend(&existing[index..]);
+    Some(updated.join("\n") + "\n")
+}
+
+/// Parses the number in `line` between `prefix` and `suffix`.
+fn number_between(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    let start = line.find(prefix)? + prefix.len();
+    let end = line[start..].find(suffix)? + start;
+    line[start..end].parse().ok()
+}
+
+/// `src/YYYY/mod.rs`, which declares the modules of a year. Created for a new year.
+pub fn year_module(contents: Option<&str>, year: u16, day: u8) -> Change {
+    let added = vec![
+        format!("#[path = \"{:02}.rs\"]", day),
+        format!("pub mod day{:02};", day),
+    ];
+
+    let contents = match contents {
+        Some(contents) if contents.lines().any(|line| line == added[1]) => {
+            return Change::Unchanged
+        }
+        Some(contents) => contents,
+        None => {
+            return Change::Update {
+                contents: format!(
+                    "//! Solutions of {}. `cargo scaffold` adds a module for every new day and registers it in `../days.rs`.\n\n{}\n",
+                    year,
+                    added.join("\n")
+                ),
+                added,
+            }
+        }
+    };
+
+    let day_of = |line: &str| number_between(line, "#[path = \"", ".rs\"]");
+    let updated = insert_sorted(
+        contents,
+        &added,
+        |line| line.starts_with("pub mod day"),
+        |line| day_of(line).is_some_and(|other| other > day as u32),
+    )
+    .unwrap_or_else(|| format!("{}\n{}\n", contents.trim_end(), added.join("\n")));
+
+    Change::Update {
+        contents: updated,
+        added,
+    }
+}
+
+/// `src/days.rs`: the module of the year and the entry of the day in `all()`.
+pub fn registry(contents: &str, year: u16, day: u8) -> Change {
+    let mut contents = String::from(contents);
+    let mut added = Vec::new();
+
+    let module = vec![
+        format!("#[path = \"{}/mod.rs\"]", year),
+        format!("pub mod y{};", year),
+    ];
+    if !contents.lines().any(|line| line == module[1]) {
+        let year_of = |line: &str| number_between(line, "#[path = \"", "/mod.rs\"]");
+        contents = match insert_sorted(
+            &contents,
+            &module,
+            |line| line.starts_with("pub mod y"),
+            |line| year_of(line).is_some_and(|other| other > year as u32),
+        )
+        .or_else(|| {
+            insert_sorted(
+                &contents,
+                &module,
+                |line| line.starts_with("use "),
+                |_| false,
+            )
+        }) {
+            Some(updated) => updated,
+            None => return Change::Skipped(String::from("could not find where modules go")),
+        };
+        added.extend(module);
+    }
+
+    let entry = format!(
+        "        Day::of::<y{year}::day{day:02}::Day{day:02}>(),",
+        year = year,
+        day = day
+    );
+    if !contents.lines().any(|line| line == entry) {
+        let key = |line: &str| {
+            let year = number_between(line, "Day::of::<y", "::day")?;
+            let day = number_between(line, &format!("<y{}::day", year), "::Day")?;
+            Some((year, day))
+        };
+        contents = match insert_sorted(
+            &contents,
+            std::slice::from_ref(&entry),
+            |line| {
+                line.trim_start().starts_with("Day::of::<") || line.trim_end().ends_with("vec![")
+            },
+            |line| key(line).is_some_and(|other| other > (year as u32, day as u32)),
+        ) {
+            Some(updated) => updated,
+            None => return Change::Skipped(String::from("could not find the list in `all()`")),
+        };
+        added.push(String::from(entry.trim()));
+    }
+
+    if added.is_empty() {
+        Change::Unchanged
+    } else {
+        Change::Update { contents, added }
+    }
+}
+
+/// The stars table of the readme, as long as it shows `year`. The row is empty until the
+/// readme-stars workflow fills it in.
+pub fn readme(contents: &str, year: u16, day: u8) -> Change {
+    let start = match contents.find(STARS_MARKER) {
+        Some(start) => start + STARS_MARKER.len(),
+        None => return Change::Skipped(String::from("there is no stars table")),
+    };
+    let end = match contents[start..].find(STARS_MARKER) {
+        Some(end) => start + end,
+        None => return Change::Skipped(String::from("the stars table is not closed")),
+    };
+    let table = &contents[start..end];
+
+    let heading = format!("## {} Results", year);
+    let row = format!(
+        "| [Day {day}](https://adventofcode.com/{year}/day/{day}) |   |   |",
+        year = year,
+        day = day
+    );
+
+    let (table, added) = if table.trim().is_empty() {
+        let added = vec![
+            heading,
+            String::new(),
+            String::from("| Day | Part 1 | Part 2 |"),
+            String::from("| :---: | :---: | :---: |"),
+            row,
+        ];
+        (format!("\n{}\n", added.join("\n")), added)
+    } else if !table.lines().any(|line| line == heading) {
+        return Change::Skipped(String::from("the stars table shows another year"));
+    } else if table.contains(&format!("[Day {}](", day)) {
+        return Change::Unchanged;
+    } else {
+        let day_of = |line: &str| number_between(line, "| [Day ", "](");
+        let table = insert_sorted(
+            table,
+            std::slice::from_ref(&row),
+            |line| line.starts_with('|'),
+            |line| day_of(line).is_some_and(|other| other > day as u32),
+        )
+        .unwrap_or_else(|| format!("{}\n", table));
+        (table, vec![row])
+    };
+
+    Change::Update {
+        contents: format!("{}{}{}", &contents[..start], table, &contents[end..]),
+        added,
+    }
+}
+
+/// A debug configuration of the day's binary in `.vscode/launch.json`.
+pub fn launch(contents: &str, year: u16, day: u8) -> Change {
+    let name = format!("Debug day {:02} of {}", day, year);
+    if contents.contains(&format!("\"name\": \"{}\"", name)) {
+        return Change::Unchanged;
+    }
+
+    // the configurations are the last array of the file.
+    let close = match contents.rfind(']') {
+        Some(close) => close,
+        None => return Change::Skipped(String::from("there is no list of configurations")),
+    };
+    let before = contents[..close].trim_end();
+    let separator = if before.ends_with('[') { "" } else { "," };
+
+    let configuration = format!(
+        r#"        {{
+            "type": "lldb",
+            "request": "launch",
+            "name": "{name}",
+            "cargo": {{
+                "args": ["build", "--bin={day:02}", "--package=aoc"],
+                "filter": {{
+                    "name": "{day:02}",
+                    "kind": "bin"
+                }}
+            }},
+            "args": ["--year", "{year}"],
+            "cwd": "${{workspaceFolder}}"
+        }}"#,
+        name = name,
+        day = day,
+        year = year
+    );
+
+    Change::Update {
+        contents: format!(
+            "{}{}\n{}\n    {}",
+            before,
+            separator,
+            configuration,
+            &contents[close..]
+        ),
+        added: vec![format!("configuration \"{}\"", name)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn updated(change: Change) -> String {
+        match change {
+            Change::Update { contents, .. } => contents,
+            other => panic!("expected an update, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_year_module() {
+        let contents = "//! Solutions of 2022.\n\n#[path = \"01.rs\"]\npub mod day01;\n#[path = \"03.rs\"]\npub mod day03;\n";
+        let contents = updated(year_module(Some(contents), 2022, 2));
+        assert_eq!(
+            contents,
+            "//! Solutions of 2022.\n\n#[path = \"01.rs\"]\npub mod day01;\n#[path = \"02.rs\"]\npub mod day02;\n#[path = \"03.rs\"]\npub mod day03;\n"
+        );
+        assert_eq!(year_module(Some(&contents), 2022, 2), Change::Unchanged);
+
+        let contents = updated(year_module(Some(&contents), 2022, 4));
+        assert!(contents.ends_with("pub mod day03;\n#[path = \"04.rs\"]\npub mod day04;\n"));
+
+        let contents = updated(year_module(None, 2023, 1));
+        assert!(contents.starts_with("//! Solutions of 2023."));
+        assert!(contents.ends_with("\n\n#[path = \"01.rs\"]\npub mod day01;\n"));
+    }
+
+    #[test]
+    fn test_registry() {
+        let contents = "use crate::Day;\n\n#[path = \"2022/mod.rs\"]\npub mod y2022;\n\npub fn all() -> Vec<Day> {\n    vec![\n        Day::of::<y2022::day01::Day01>(),\n        Day::of::<y2022::day03::Day03>(),\n    ]\n}\n";
+
+        let change = registry(contents, 2022, 2);
+        assert!(matches!(&change, Change::Update { added, .. } if added.len() == 1));
+        let contents = updated(change);
+        assert!(contents.contains("        Day::of::<y2022::day01::Day01>(),\n        Day::of::<y2022::day02::Day02>(),\n        Day::of::<y2022::day03::Day03>(),\n"));
+        assert_eq!(registry(&contents, 2022, 2), Change::Unchanged);
+
+        let contents = updated(registry(&contents, 2021, 5));
+        assert!(contents.contains("use crate::Day;\n\n#[path = \"2021/mod.rs\"]\npub mod y2021;\n#[path = \"2022/mod.rs\"]\npub mod y2022;\n"));
+        assert!(contents.contains("    vec![\n        Day::of::<y2021::day05::Day05>(),\n        Day::of::<y2022::day01::Day01>(),\n"));
+
+        let contents = updated(registry(&contents, 2023, 1));
+        assert!(contents.contains("pub mod y2022;\n#[path = \"2023/mod.rs\"]\npub mod y2023;\n"));
+        assert!(contents.contains("        Day::of::<y2022::day03::Day03>(),\n        Day::of::<y2023::day01::Day01>(),\n    ]\n"));
+
+        assert!(matches!(
+            registry("pub fn all() {}\n", 2022, 1),
+            Change::Skipped(_)
+        ));
+    }
+
+    #[test]
+    fn test_readme() {
+        let contents = format!(
+            "# AoC\n{m}\n## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n| [Day 10](https://adventofcode.com/2022/day/10) | ⭐ |   |\n{m}\nrest\n",
+            m = STARS_MARKER
+        );
+
+        let contents = updated(readme(&contents, 2022, 2));
+        assert!(contents.contains(
+            "| ⭐ | ⭐ |\n| [Day 2](https://adventofcode.com/2022/day/2) |   |   |\n| [Day 10]"
+        ));
+        assert!(contents.ends_with(&format!("|   |\n{}\nrest\n", STARS_MARKER)));
+        assert_eq!(readme(&contents, 2022, 2), Change::Unchanged);
+
+        let contents = updated(readme(&contents, 2022, 11));
+        assert!(contents.contains(&format!(
+            "| [Day 11](https://adventofcode.com/2022/day/11) |   |   |\n{}",
+            STARS_MARKER
+        )));
+
+        assert!(matches!(readme(&contents, 2021, 1), Change::Skipped(_)));
+        assert!(matches!(readme("# AoC\n", 2022, 1), Change::Skipped(_)));
+
+        let empty = format!("{m}\n{m}\n", m = STARS_MARKER);
+        assert_eq!(
+            updated(readme(&empty, 2023, 1)),
+            format!("{m}\n## 2023 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2023/day/1) |   |   |\n{m}\n", m = STARS_MARKER)
+        );
+    }
+
+    #[test]
+    fn test_launch() {
+        let contents = "{\n    // comment\n    \"configurations\": [\n        {\n            \"name\": \"other\"\n        }\n    ]\n}\n";
+
+        let contents = updated(launch(contents, 2022, 3));
+        assert!(contents.contains("        },\n        {\n            \"type\": \"lldb\",\n"));
+        assert!(contents.contains("\"name\": \"Debug day 03 of 2022\""));
+        assert!(contents.contains("\"args\": [\"build\", \"--bin=03\", \"--package=aoc\"]"));
+        assert!(contents.contains("\"args\": [\"--year\", \"2022\"],"));
+        assert!(contents.ends_with("        }\n    ]\n}\n"));
+        assert_eq!(launch(&contents, 2022, 3), Change::Unchanged);
+
+        let contents = updated(launch("{\"configurations\": []}", 2022, 3));
+        assert!(contents.starts_with("{\"configurations\": [\n        {\n"));
+        assert!(contents.ends_with("        }\n    ]}"));
+    }
+}
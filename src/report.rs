@@ -0,0 +1,181 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::json;
+use crate::memory::AllocStats;
+use crate::runner::{DayResult, PartResult};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: json, csv, text",
+                s
+            )),
+        }
+    }
+}
+
+fn rows(results: &[DayResult]) -> impl Iterator<Item = (&DayResult, &PartResult)> {
+    results
+        .iter()
+        .flat_map(|day| day.parts.iter().map(move |part| (day, part)))
+}
+
+/// Time spent parsing the input of `day`, shared by both of its parts.
+fn parse_field(day: &DayResult, missing: &str) -> String {
+    day.parse.as_ref().map_or(String::from(missing), |parse| {
+        parse.elapsed.as_nanos().to_string()
+    })
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        String::from(s)
+    }
+}
+
+/// Formats one of the allocation statistics, or `missing` if they weren't recorded.
+fn memory_field(part: &PartResult, field: fn(&AllocStats) -> u64, missing: &str) -> String {
+    part.memory
+        .as_ref()
+        .map_or(String::from(missing), |m| field(m).to_string())
+}
+
+/// One JSON object per part, wrapped in an array.
+pub fn json(results: &[DayResult]) -> String {
+    let objects: Vec<String> = rows(results)
+        .map(|(day, part)| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"status\": {}, \"verdict\": {}, \"parse_ns\": {}, \"elapsed_ns\": {}, \"alloc_bytes\": {}, \"alloc_count\": {}, \"peak_bytes\": {}}}",
+                day.day,
+                part.part,
+                part.answer
+                    .as_deref()
+                    .map_or(String::from("null"), json::string),
+                json::string(part.status.as_str()),
+                part.verdict
+                    .as_ref()
+                    .map_or(String::from("null"), |v| json::string(v.as_str())),
+                parse_field(day, "null"),
+                part.elapsed.as_nanos(),
+                memory_field(part, |m| m.bytes, "null"),
+                memory_field(part, |m| m.count, "null"),
+                memory_field(part, |m| m.peak, "null")
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+/// One CSV row per part, with a header row.
+pub fn csv(results: &[DayResult]) -> String {
+    let mut csv = String::from(
+        "day,part,answer,status,verdict,parse_ns,elapsed_ns,alloc_bytes,alloc_count,peak_bytes\n",
+    );
+    for (day, part) in rows(results) {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{}\n",
+            day.day,
+            part.part,
+            csv_field(part.answer.as_deref().unwrap_or_default()),
+            part.status.as_str(),
+            part.verdict.as_ref().map_or("", |v| v.as_str()),
+            parse_field(day, ""),
+            part.elapsed.as_nanos(),
+            memory_field(part, |m| m.bytes, ""),
+            memory_field(part, |m| m.count, ""),
+            memory_field(part, |m| m.peak, "")
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Verdict;
+    use crate::runner::{ParseResult, Status};
+    use std::time::Duration;
+
+    fn results() -> Vec<DayResult> {
+        vec![DayResult {
+            day: 5,
+            title: "Supply Stacks",
+            parse: Some(ParseResult {
+                elapsed: Duration::from_nanos(300),
+                memory: None,
+                timed_out: false,
+            }),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Some(String::from("CMZ")),
+                    status: Status::Solved,
+                    verdict: Some(Verdict::Pass),
+                    elapsed: Duration::from_nanos(1500),
+                    memory: Some(AllocStats {
+                        bytes: 4096,
+                        count: 3,
+                        peak: 2048,
+                    }),
+                },
+                PartResult {
+                    part: 2,
+                    answer: None,
+                    status: Status::Unsolved,
+                    verdict: None,
+                    elapsed: Duration::from_nanos(20),
+                    memory: None,
+                },
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            json(&results()),
+            "[\n  {\"day\": 5, \"part\": 1, \"answer\": \"CMZ\", \"status\": \"solved\", \"verdict\": \"PASS\", \"parse_ns\": 300, \"elapsed_ns\": 1500, \"alloc_bytes\": 4096, \"alloc_count\": 3, \"peak_bytes\": 2048},\n  {\"day\": 5, \"part\": 2, \"answer\": null, \"status\": \"unsolved\", \"verdict\": null, \"parse_ns\": 300, \"elapsed_ns\": 20, \"alloc_bytes\": null, \"alloc_count\": null, \"peak_bytes\": null}\n]\n"
+        );
+        assert_eq!(json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&results()),
+            "day,part,answer,status,verdict,parse_ns,elapsed_ns,alloc_bytes,alloc_count,peak_bytes\n\
+             5,1,CMZ,solved,PASS,300,1500,4096,3,2048\n\
+             5,2,,unsolved,,300,20,,,\n"
+        );
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
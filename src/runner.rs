@@ -0,0 +1,677 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Verdict;
+use crate::filter::Filter;
+use crate::memory::{self, AllocStats};
+use crate::report::{self, Format};
+use crate::{Day, InputSource, Parsed, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::ffi::OsString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use std::{env, panic, process, thread};
+
+/// Worker threads get the main thread's usual stack size so deep recursion behaves the same.
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Time a single part of `cargo all` may take before it is reported as timed out.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    NoInput,
+    Timeout,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::NoInput => "no-input",
+            Status::Timeout => "timeout",
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// Comparison with `src/YYYY/answers.toml`, if the part was checked.
+    pub verdict: Option<Verdict>,
+    pub elapsed: Duration,
+    /// Allocations made by the part, if the `alloc-stats` feature is enabled.
+    pub memory: Option<AllocStats>,
+}
+
+impl PartResult {
+    fn timed_out(part: u8, elapsed: Duration) -> Self {
+        PartResult {
+            part,
+            answer: None,
+            status: Status::Timeout,
+            verdict: None,
+            elapsed,
+            memory: None,
+        }
+    }
+}
+
+/// Time and allocations of the parse step, see [`crate::Solution::parse`].
+pub struct ParseResult {
+    pub elapsed: Duration,
+    pub memory: Option<AllocStats>,
+    pub timed_out: bool,
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    /// `None` if the input was never parsed.
+    pub parse: Option<ParseResult>,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    /// Result for a day that could not be run because its input file is missing.
+    pub fn without_input(day: &Day, parts: &[u8]) -> Self {
+        DayResult {
+            day: day.day,
+            title: day.title,
+            parse: None,
+            parts: parts
+                .iter()
+                .map(|&part| PartResult {
+                    part,
+                    answer: None,
+                    status: Status::NoInput,
+                    verdict: None,
+                    elapsed: Duration::ZERO,
+                    memory: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Time spent parsing and solving.
+    pub fn elapsed(&self) -> Duration {
+        self.parse
+            .as_ref()
+            .map_or(Duration::ZERO, |parse| parse.elapsed)
+            + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+
+    /// Whether parsing or a part ran into the timeout. Its thread keeps running in the
+    /// background, so timings taken after it are skewed.
+    pub fn timed_out(&self) -> bool {
+        self.parse.as_ref().is_some_and(|parse| parse.timed_out)
+            || self.parts.iter().any(|part| part.status == Status::Timeout)
+    }
+}
+
+/// Calls `f` and measures its run time and allocations.
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<AllocStats>) {
+    let ((value, elapsed), memory) = memory::measure(|| {
+        let timer = Instant::now();
+        let value = f();
+        (value, timer.elapsed())
+    });
+    (value, elapsed, memory)
+}
+
+pub fn run_parse(day: &Day, input: &str) -> (Parsed, ParseResult) {
+    let (parsed, elapsed, memory) = measure(|| (day.parse)(input));
+    (
+        parsed,
+        ParseResult {
+            elapsed,
+            memory,
+            timed_out: false,
+        },
+    )
+}
+
+pub fn run_part(day: &Day, part: u8, parsed: &dyn Any) -> PartResult {
+    // only the solution is measured, not turning its answer into a string.
+    let mut measured = None;
+    let answer = day.part(part)(parsed, &mut |solve| measured = Some(measure(solve)));
+    let ((), elapsed, memory) = measured.expect("the solution was not run");
+
+    let status = if answer.is_some() {
+        Status::Solved
+    } else {
+        Status::Unsolved
+    };
+
+    PartResult {
+        part,
+        answer,
+        status,
+        verdict: None,
+        elapsed,
+        memory,
+    }
+}
+
+enum Step {
+    Parse(ParseResult),
+    Part(PartResult),
+}
+
+/// Parses `input` and runs `parts` on it, passing every finished step to `report`.
+/// Stops early if `report` returns `false`.
+fn run_steps(day: &Day, input: &str, parts: &[u8], mut report: impl FnMut(Step) -> bool) {
+    let (parsed, parse) = run_parse(day, input);
+    if !report(Step::Parse(parse)) {
+        return;
+    }
+
+    for &part in parts {
+        if !report(Step::Part(run_part(day, part, &*parsed))) {
+            return;
+        }
+    }
+}
+
+/// Runs the steps of a day on their own thread and stops waiting for a step after `timeout`.
+/// A timed out step can't be stopped, it keeps running in the background until the process
+/// exits. The parts after it are run on a new thread, which parses the input again.
+fn run_day_with_timeout(day: &Day, input: &str, parts: &[u8], timeout: Duration) -> DayResult {
+    let input: Arc<str> = Arc::from(input);
+    let mut result = DayResult {
+        day: day.day,
+        title: day.title,
+        parse: None,
+        parts: Vec::new(),
+    };
+    let mut remaining = parts.to_vec();
+
+    loop {
+        let (sender, receiver) = mpsc::channel();
+        let (day, input, parts) = (*day, Arc::clone(&input), remaining.clone());
+        let handle = thread::Builder::new()
+            .stack_size(WORKER_STACK_SIZE)
+            // the receiver is gone if a step timed out.
+            .spawn(move || run_steps(&day, &input, &parts, |step| sender.send(step).is_ok()))
+            .expect("could not spawn solver thread");
+
+        let mut parsed = false;
+        loop {
+            let timer = Instant::now();
+            match receiver.recv_timeout(timeout) {
+                Ok(Step::Parse(parse)) => {
+                    // only the first parse is reported, restarts parse again.
+                    result.parse.get_or_insert(parse);
+                    parsed = true;
+                }
+                Ok(Step::Part(part)) => {
+                    remaining.remove(0);
+                    result.parts.push(part);
+                }
+                Err(RecvTimeoutError::Timeout) if parsed => {
+                    result
+                        .parts
+                        .push(PartResult::timed_out(remaining.remove(0), timer.elapsed()));
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    result.parse.get_or_insert(ParseResult {
+                        elapsed: timer.elapsed(),
+                        memory: None,
+                        timed_out: true,
+                    });
+                    // no part can run without a parsed input.
+                    for part in remaining.drain(..) {
+                        result
+                            .parts
+                            .push(PartResult::timed_out(part, Duration::ZERO));
+                    }
+                    break;
+                }
+                // the solver panicked, pass the panic on as if it had run on this thread.
+                Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                    Err(payload) => panic::resume_unwind(payload),
+                    Ok(()) => break,
+                },
+            }
+        }
+
+        if remaining.is_empty() {
+            return result;
+        }
+    }
+}
+
+/// Parses `input` and runs the given `parts` of `day` on it, in order. Steps that take
+/// longer than `timeout` are reported as timed out.
+pub fn run_day(day: &Day, input: &str, parts: &[u8], timeout: Option<Duration>) -> DayResult {
+    if let Some(timeout) = timeout {
+        return run_day_with_timeout(day, input, parts, timeout);
+    }
+
+    let mut result = DayResult {
+        day: day.day,
+        title: day.title,
+        parse: None,
+        parts: Vec::new(),
+    };
+    run_steps(day, input, parts, |step| {
+        match step {
+            Step::Parse(parse) => result.parse = Some(parse),
+            Step::Part(part) => result.parts.push(part),
+        }
+        true
+    });
+    result
+}
+
+/// Parses a timeout in seconds. `0` disables the timeout.
+pub fn parse_timeout(s: &str) -> Result<Option<Duration>, String> {
+    let seconds: f64 = s.parse().map_err(|e| format!("{}", e))?;
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(Duration::ZERO) => Ok(None),
+        Ok(timeout) => Ok(Some(timeout)),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+/// Runs `job` for every day on up to `jobs` worker threads. Results are passed to `emit`
+/// in the order of `days`, as soon as all earlier days have finished.
+pub fn run_ordered<F, E>(days: &[Day], jobs: usize, job: F, mut emit: E)
+where
+    F: Fn(&Day) -> DayResult + Sync,
+    E: FnMut(DayResult),
+{
+    if jobs <= 1 {
+        days.iter().for_each(|day| emit(job(day)));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (next, job, sender) = (&next, &job, sender.clone());
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= days.len() || sender.send((index, job(&days[index]))).is_err() {
+                        break;
+                    }
+                })
+                .expect("could not spawn worker thread");
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&expected) {
+                emit(result);
+                expected += 1;
+            }
+        }
+    });
+}
+
+pub fn print_parse(result: &ParseResult) {
+    println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+    if result.timed_out {
+        println!(
+            "{}TIMEOUT{} {}(elapsed: {:.2?}){}",
+            ANSI_RED, ANSI_RESET, ANSI_ITALIC, result.elapsed, ANSI_RESET
+        );
+        return;
+    }
+
+    let memory = match &result.memory {
+        Some(memory) => format!(", {}", memory),
+        None => String::new(),
+    };
+    println!(
+        "{}(elapsed: {:.2?}{}){}",
+        ANSI_ITALIC, result.elapsed, memory, ANSI_RESET
+    );
+}
+
+pub fn print_part(result: &PartResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    let verdict = match &result.verdict {
+        None => String::new(),
+        Some(Verdict::Fail { expected }) => {
+            format!(" {}FAIL (expected {}){}", ANSI_RED, expected, ANSI_RESET)
+        }
+        Some(verdict) => format!(" {}", verdict.as_str()),
+    };
+
+    let memory = match &result.memory {
+        Some(memory) => format!(", {}", memory),
+        None => String::new(),
+    };
+
+    match &result.answer {
+        None if result.status == Status::Timeout => {
+            println!(
+                "{}TIMEOUT{} {}(elapsed: {:.2?}){}",
+                ANSI_RED, ANSI_RESET, ANSI_ITALIC, result.elapsed, ANSI_RESET
+            );
+        }
+        Some(answer) => {
+            println!(
+                "{} {}(elapsed: {:.2?}{}){}{}",
+                answer, ANSI_ITALIC, result.elapsed, memory, ANSI_RESET, verdict
+            );
+        }
+        None => {
+            println!("not solved.{}", verdict)
+        }
+    }
+}
+
+pub fn print_day(result: &DayResult) {
+    print_day_header(result.day);
+
+    if result
+        .parts
+        .iter()
+        .all(|part| part.status == Status::NoInput)
+    {
+        println!("Not solved (no input file).");
+    } else {
+        print_steps(result);
+    }
+}
+
+/// Prints the parse step and every part of `result`.
+pub fn print_steps(result: &DayResult) {
+    if let Some(parse) = &result.parse {
+        print_parse(parse);
+    }
+    result.parts.iter().for_each(print_part);
+}
+
+pub fn print_day_header(day: u8) {
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+}
+
+pub fn print_total(total: Duration) {
+    println!(
+        "{}Total:{} {}{:.2}ms{}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        ANSI_RESET
+    );
+}
+
+struct SolveOptions {
+    year: u16,
+    format: Format,
+    filter: Filter,
+    timeout: Option<Duration>,
+    /// Replaces `src/YYYY/inputs/NN.txt` if set.
+    input: Option<InputSource>,
+    watch: bool,
+}
+
+fn parse_solve_args() -> Result<SolveOptions, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args
+        .opt_value_from_fn(["-y", "--year"], crate::parse_year)?
+        .unwrap_or_else(crate::default_year);
+    let format = args.opt_value_from_str("--format")?.unwrap_or(Format::Text);
+    let filter = Filter::from_args(&mut args)?;
+    let timeout = args
+        .opt_value_from_fn("--timeout", parse_timeout)?
+        .flatten();
+    let input = args
+        .opt_value_from_os_str("--input", |s| Ok::<_, Infallible>(InputSource::from(s)))?
+        .or_else(|| {
+            env::var_os(crate::INPUT_ENV)
+                .filter(|s| !s.is_empty())
+                .map(|s| InputSource::from(s.as_os_str()))
+        });
+    let watch = args.contains("--watch");
+    reject_remaining(args)?;
+
+    if filter.days.is_some() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: String::from("--days only applies to `cargo all`"),
+        });
+    }
+    Ok(SolveOptions {
+        year,
+        format,
+        filter,
+        timeout,
+        input,
+        watch,
+    })
+}
+
+/// Fails on arguments that were not consumed, so typos don't go unnoticed.
+pub fn reject_remaining(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let remaining = args.finish();
+    if remaining.is_empty() {
+        return Ok(());
+    }
+
+    Err(pico_args::Error::ArgumentParsingFailed {
+        cause: format!(
+            "unexpected argument(s) {}",
+            remaining
+                .iter()
+                .map(|arg| format!("\"{}\"", arg.to_string_lossy()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    })
+}
+
+/// Entry point of the `src/bin/NN.rs` binaries: runs both parts of `day` of the selected year
+/// against its input.
+pub fn solve(day: u8) {
+    let SolveOptions {
+        year,
+        format,
+        filter,
+        timeout,
+        input,
+        watch,
+    } = match parse_solve_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let day = match crate::days::get(year, day) {
+        Some(day) => day,
+        None => {
+            eprintln!(
+                "Day {:02} of {} is not in the registry (src/days.rs).",
+                day, year
+            );
+            process::exit(1);
+        }
+    };
+
+    if watch {
+        let args: Vec<OsString> = env::args_os()
+            .skip(1)
+            .filter(|arg| arg != "--watch")
+            .collect();
+        crate::watch::watch(&day, &args);
+    }
+
+    let input = match input {
+        Some(source) => {
+            eprintln!("Reading input from {}.", source);
+            source.read()
+        }
+        None => crate::read_file(day.year, "inputs", day.day),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let mut result = run_day(&day, &input, &filter.parts(), timeout);
+    filter.retain(&mut result);
+
+    match format {
+        Format::Text => print_steps(&result),
+        Format::Json => print!("{}", report::json(&[result])),
+        Format::Csv => print!("{}", report::csv(&[result])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, read_file};
+
+    #[test]
+    fn test_run_ordered() {
+        let days = days::all();
+        let mut emitted = Vec::new();
+
+        run_ordered(
+            &days,
+            4,
+            |day| {
+                let input = read_file(day.year, "examples", day.day).unwrap();
+                run_day(day, &input, &[1, 2], None)
+            },
+            |result| emitted.push(result.day),
+        );
+
+        assert_eq!(emitted, days.iter().map(|day| day.day).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_run_day() {
+        let day = days::get(2022, 2).unwrap();
+        let input = read_file(2022, "examples", 2).unwrap();
+        let result = run_day(&day, &input, &[1, 2], None);
+        assert_eq!(result.day, 2);
+
+        assert!(result.parse.is_some());
+
+        let answers: Vec<Option<String>> = result.parts.into_iter().map(|p| p.answer).collect();
+        assert_eq!(
+            answers,
+            vec![Some(String::from("15")), Some(String::from("12"))]
+        );
+
+        let result = run_day(&day, &input, &[2], Some(DEFAULT_TIMEOUT));
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].part, 2);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let day = Day {
+            year: 2022,
+            day: 1,
+            title: "Slow",
+            parse: |input| Box::new(String::from(input)),
+            part_one: |_, run| {
+                run(&mut || thread::sleep(Duration::from_millis(500)));
+                Some(String::from("late"))
+            },
+            part_two: |parsed, run| {
+                run(&mut || {});
+                parsed.downcast_ref::<String>().cloned()
+            },
+        };
+
+        let result = run_day(&day, "fast", &[2], Some(Duration::from_millis(20)));
+        assert!(!result.timed_out());
+
+        let result = run_day(&day, "fast", &[1, 2], Some(Duration::from_millis(20)));
+        assert!(result.timed_out());
+        assert!(!result.parse.unwrap().timed_out);
+        assert_eq!(result.parts[0].status, Status::Timeout);
+        assert_eq!(result.parts[0].answer, None);
+        assert!(result.parts[0].elapsed >= Duration::from_millis(20));
+        // part two still runs after part one timed out.
+        assert_eq!(result.parts[1].status, Status::Solved);
+        assert_eq!(result.parts[1].answer.as_deref(), Some("fast"));
+
+        let day = Day {
+            parse: |_| {
+                thread::sleep(Duration::from_millis(500));
+                Box::new(())
+            },
+            ..day
+        };
+        let result = run_day(&day, "fast", &[1, 2], Some(Duration::from_millis(20)));
+        assert!(result.timed_out());
+        assert!(result.parse.unwrap().timed_out);
+        assert!(result
+            .parts
+            .iter()
+            .all(|part| part.status == Status::Timeout));
+    }
+
+    #[test]
+    fn test_run_part_memory() {
+        struct Large;
+        impl crate::Solution for Large {
+            const YEAR: u16 = 2022;
+            const DAY: u8 = 1;
+            const TITLE: &'static str = "Large";
+            type Parsed = u64;
+            type PartOne = u64;
+            type PartTwo = u64;
+
+            fn parse(input: &str) -> u64 {
+                input.parse().unwrap()
+            }
+
+            fn part_one(parsed: &u64) -> Option<u64> {
+                Some(parsed * 1_000_000_007)
+            }
+
+            fn part_two(_: &u64) -> Option<u64> {
+                None
+            }
+        }
+
+        let day = Day::of::<Large>();
+        let result = run_part(&day, 1, &*(day.parse)("12345"));
+        assert_eq!(result.answer.as_deref(), Some("12345000086415"));
+        // turning the answer into a string is not counted.
+        assert_eq!(
+            result.memory.map(|memory| memory.count),
+            crate::memory::ENABLED.then_some(0)
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("1.5"), Ok(Some(Duration::from_millis(1500))));
+        assert_eq!(parse_timeout("0"), Ok(None));
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+}
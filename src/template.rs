@@ -0,0 +1,212 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Renders the module that `cargo scaffold` creates for a day. A project can replace the
+//! built-in [`MODULE_TEMPLATE`] with its own file, [`TEMPLATE_PATH`] unless `--template` is
+//! passed. Templates can use these placeholders:
+//!
+//! - `{day}`: `1`
+//! - `{day_padded}`: `01`
+//! - `{year}`: `2022`
+//! - `{title}`: `Calorie Counting`, escaped for a string literal. Empty unless the puzzle was
+//!   fetched with `--puzzle`.
+//! - `{part_one_type}`, `{part_two_type}`: output types of the parts. `u64` unless the
+//!   expected answer needs `i64` or `String`.
+//! - `{part_one_answer}`, `{part_two_answer}`: the expected answers for the example, e.g.
+//!   `Some(24000)`, or `None`.
+//!
+//! Other text in braces is left as it is.
+
+use std::path::Path;
+use std::{fs, io};
+
+/// Where `cargo scaffold` looks for a project template.
+pub const TEMPLATE_PATH: &str = "templates/day.rs";
+
+pub const MODULE_TEMPLATE: &str = r###"use aoc::Solution;
+
+pub struct Day{day_padded};
+
+impl Solution for Day{day_padded} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    const TITLE: &'static str = "{title}";
+
+    type Parsed = String;
+    type PartOne = {part_one_type};
+    type PartTwo = {part_two_type};
+
+    fn parse(input: &str) -> String {
+        String::from(input)
+    }
+
+    fn part_one(_input: &String) -> Option<{part_one_type}> {
+        None
+    }
+
+    fn part_two(_input: &String) -> Option<{part_two_type}> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod day{day} {
+        use super::super::*;
+
+        #[test]
+        fn test_part_one() {
+            let input = aoc::read_file({year}, "examples", {day}).unwrap();
+            assert_eq!(Day{day_padded}::part_one(&Day{day_padded}::parse(&input)), {part_one_answer});
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = aoc::read_file({year}, "examples", {day}).unwrap();
+            assert_eq!(Day{day_padded}::part_two(&Day{day_padded}::parse(&input)), {part_two_answer});
+        }
+    }
+}
+"###;
+
+/// What is known about a day when it is scaffolded.
+#[derive(Debug, Default)]
+pub struct Placeholders {
+    pub day: u8,
+    pub year: u16,
+    pub title: Option<String>,
+    /// Expected answers for the example, by part.
+    pub answers: Vec<Option<String>>,
+}
+
+/// The output type of a part and the expected value in its test, e.g. `u64` and `Some(24000)`.
+/// Real inputs give much larger answers than the example, so numbers are never narrower than
+/// `u64`.
+fn expected(answer: Option<&String>) -> (&'static str, String) {
+    match answer {
+        None => ("u64", String::from("None")),
+        Some(answer) if answer.parse::<u64>().is_ok() => ("u64", format!("Some({})", answer)),
+        Some(answer) if answer.parse::<i64>().is_ok() => ("i64", format!("Some({})", answer)),
+        Some(answer) => ("String", format!("Some(String::from({:?}))", answer)),
+    }
+}
+
+impl Placeholders {
+    fn value(&self, name: &str) -> Option<String> {
+        let answer = |part: usize| expected(self.answers.get(part).and_then(Option::as_ref));
+
+        match name {
+            "day" => Some(self.day.to_string()),
+            "day_padded" => Some(format!("{:02}", self.day)),
+            "year" => Some(self.year.to_string()),
+            "title" => Some(
+                self.title
+                    .as_deref()
+                    .unwrap_or_default()
+                    .escape_debug()
+                    .to_string(),
+            ),
+            "part_one_type" => Some(String::from(answer(0).0)),
+            "part_two_type" => Some(String::from(answer(1).0)),
+            "part_one_answer" => Some(answer(0).1),
+            "part_two_answer" => Some(answer(1).1),
+            _ => None,
+        }
+    }
+}
+
+/// Replaces the placeholders in `template` in a single pass, so values that contain braces
+/// are not replaced again.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest
+            .find('}')
+            .and_then(|end| Some((end, placeholders.value(&rest[1..end])?)));
+        match value {
+            Some((end, value)) => {
+                rendered.push_str(&value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Reads the project template at `path`, or `None` if there is no such file.
+pub fn load(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(template) if template.trim().is_empty() => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the template is empty",
+        )),
+        Ok(template) => Ok(Some(template)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let placeholders = Placeholders {
+            day: 5,
+            year: 2022,
+            title: Some(String::from("Say \"{day}\"")),
+            answers: vec![Some(String::from("CMZ")), Some(String::from("-12"))],
+        };
+
+        assert_eq!(
+            render(
+                "{year}/{day_padded}: {title} {part_one_type}={part_one_answer}, {part_two_type}={part_two_answer} {day} {unknown} {{}}",
+                &placeholders
+            ),
+            "2022/05: Say \\\"{day}\\\" String=Some(String::from(\"CMZ\")), i64=Some(-12) 5 {unknown} {{}}"
+        );
+    }
+
+    #[test]
+    fn test_expected() {
+        let answer = |s: &str| expected(Some(&String::from(s)));
+        assert_eq!(answer("24"), ("u64", String::from("Some(24)")));
+        assert_eq!(
+            answer("4294967296"),
+            ("u64", String::from("Some(4294967296)"))
+        );
+        assert_eq!(answer("-3"), ("i64", String::from("Some(-3)")));
+        assert_eq!(expected(None), ("u64", String::from("None")));
+    }
+
+    #[test]
+    fn test_render_default() {
+        let placeholders = Placeholders {
+            day: 1,
+            year: 2022,
+            ..Placeholders::default()
+        };
+        let module = render(MODULE_TEMPLATE, &placeholders);
+
+        assert!(module.contains("pub struct Day01;"));
+        assert!(module.contains("const TITLE: &'static str = \"\";"));
+        assert!(module.contains("type PartOne = u64;"));
+        assert!(module.contains("mod day1 {"));
+        assert!(module.contains("assert_eq!(Day01::part_two(&Day01::parse(&input)), None);"));
+        assert!(!module.contains("{day"));
+    }
+}
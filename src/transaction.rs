@@ -0,0 +1,126 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Groups file writes so that they can be undone together, which lets `cargo scaffold` leave
+//! the project as it was if any step fails.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+enum Undo {
+    RemoveDir(PathBuf),
+    RemoveFile(PathBuf),
+    Restore { path: PathBuf, contents: String },
+}
+
+/// Records how to undo every write. Call [`Transaction::rollback`] to undo them, or drop the
+/// transaction to keep them.
+#[derive(Default)]
+pub struct Transaction {
+    undo: Vec<Undo>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Transaction::default()
+    }
+
+    /// Creates `path` and its missing parents.
+    pub fn create_dir_all(&mut self, path: &Path) -> io::Result<()> {
+        let missing: Vec<&Path> = path
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .collect();
+
+        for dir in missing.into_iter().rev() {
+            fs::create_dir(dir)?;
+            self.undo.push(Undo::RemoveDir(dir.to_path_buf()));
+        }
+        Ok(())
+    }
+
+    /// Writes `contents` to `path` atomically, creating its folder if needed. An existing file
+    /// is restored on rollback.
+    pub fn write(&mut self, path: &Path, contents: &str) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            self.create_dir_all(parent)?;
+        }
+
+        let undo = match fs::read_to_string(path) {
+            Ok(previous) => Undo::Restore {
+                path: path.to_path_buf(),
+                contents: previous,
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Undo::RemoveFile(path.to_path_buf()),
+            Err(e) => return Err(e),
+        };
+
+        crate::write_atomic(path, contents)?;
+        self.undo.push(undo);
+        Ok(())
+    }
+
+    /// Undoes all writes in reverse order. Keeps going if a step fails and returns the first
+    /// error.
+    pub fn rollback(self) -> io::Result<()> {
+        let mut result = Ok(());
+        for undo in self.undo.into_iter().rev() {
+            let step = match undo {
+                Undo::RemoveDir(path) => fs::remove_dir(path),
+                Undo::RemoveFile(path) => fs::remove_file(path),
+                Undo::Restore { path, contents } => crate::write_atomic(&path, &contents),
+            };
+            if result.is_ok() {
+                result = step;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_rollback() {
+        let dir = env::temp_dir().join(format!("aoc-transaction-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let existing = dir.join("existing.txt");
+        fs::write(&existing, "before").unwrap();
+
+        let mut transaction = Transaction::new();
+        transaction.write(&existing, "after").unwrap();
+        transaction
+            .write(&dir.join("new/nested/file.txt"), "new")
+            .unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "after");
+        assert!(dir.join("new/nested/file.txt").exists());
+
+        // writing below a file fails.
+        assert!(transaction
+            .write(&existing.join("child.txt"), "fails")
+            .is_err());
+
+        transaction.rollback().unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "before");
+        assert!(!dir.join("new").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_commit() {
+        let dir = env::temp_dir().join(format!("aoc-transaction-commit-{}", std::process::id()));
+
+        let mut transaction = Transaction::new();
+        transaction.write(&dir.join("file.txt"), "kept").unwrap();
+        drop(transaction);
+
+        assert_eq!(fs::read_to_string(dir.join("file.txt")).unwrap(), "kept");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
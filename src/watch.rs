@@ -0,0 +1,146 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! `cargo solve NN -- --watch`: re-runs a day whenever its files change. Changes are found by
+//! polling file metadata, so this works without a file watcher dependency.
+
+use crate::{input_path, year_dir, Day, ANSI_BOLD, ANSI_RESET};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Files that affect the result of `day`.
+pub fn watched_paths(day: &Day) -> Vec<PathBuf> {
+    let src = std::env::current_dir().unwrap_or_default().join("src");
+    vec![
+        year_dir(day.year).join(format!("{:02}.rs", day.day)),
+        input_path(day.year, "examples", day.day),
+        input_path(day.year, "inputs", day.day),
+        src.join("helpers.rs"),
+    ]
+}
+
+/// Modification time and length of every watched file, `None` for missing files.
+#[derive(Debug, PartialEq)]
+pub struct Snapshot(Vec<Option<(SystemTime, u64)>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Snapshot(
+            paths
+                .iter()
+                .map(|path| {
+                    let metadata = fs::metadata(path).ok()?;
+                    Some((metadata.modified().ok()?, metadata.len()))
+                })
+                .collect(),
+        )
+    }
+
+    /// Paths whose metadata differs between `self` and `other`.
+    pub fn changed<'a>(&self, other: &Snapshot, paths: &'a [PathBuf]) -> Vec<&'a Path> {
+        paths
+            .iter()
+            .zip(self.0.iter().zip(other.0.iter()))
+            .filter(|(_, (before, after))| before != after)
+            .map(|(path, _)| path.as_path())
+            .collect()
+    }
+}
+
+fn cargo(subcommand: &str, args: &[&str]) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.arg(subcommand);
+    // keep the profile the watcher was started with.
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.args(args);
+    command
+}
+
+/// Rebuilds the day, runs its tests against the example and then both parts with `args`.
+fn run(day: &Day, args: &[OsString]) {
+    let bin = format!("{:02}", day.day);
+    // solutions are tested as part of the library, where they live in `days::yYYYY::dayNN`.
+    let tests = format!("y{}::day{}::", day.year, bin);
+
+    println!("🎄 {}Testing day {}{} 🎄", ANSI_BOLD, bin, ANSI_RESET);
+    if let Err(e) = cargo("test", &["--quiet", "--lib", &tests]).status() {
+        eprintln!("Failed to run cargo test: {}", e);
+    }
+
+    println!("🎄 {}Solving day {}{} 🎄", ANSI_BOLD, bin, ANSI_RESET);
+    if let Err(e) = cargo("run", &["--quiet", "--bin", &bin])
+        .arg("--")
+        .args(args)
+        .status()
+    {
+        eprintln!("Failed to run cargo run: {}", e);
+    }
+}
+
+/// Polls the files of `day` and re-runs it on every change. Never returns.
+pub fn watch(day: &Day, args: &[OsString]) -> ! {
+    let paths = watched_paths(day);
+    println!("Watching:");
+    for path in paths.iter() {
+        println!("  {}", path.display());
+    }
+
+    let mut last = Snapshot::take(&paths);
+    run(day, args);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = Snapshot::take(&paths);
+        let changed = current.changed(&last, &paths);
+        if changed.is_empty() {
+            continue;
+        }
+
+        println!();
+        println!("==================================================");
+        for path in changed {
+            println!("Changed: {}", path.display());
+        }
+        println!("==================================================");
+
+        last = current;
+        run(day, args);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let paths = vec![dir.join("a.txt"), dir.join("b.txt")];
+        fs::write(&paths[0], "a").unwrap();
+
+        let before = Snapshot::take(&paths);
+        assert!(Snapshot::take(&paths).changed(&before, &paths).is_empty());
+
+        fs::write(&paths[0], "aa").unwrap();
+        fs::write(&paths[1], "b").unwrap();
+        let after = Snapshot::take(&paths);
+        assert_eq!(
+            after.changed(&before, &paths),
+            vec![paths[0].as_path(), paths[1].as_path()]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
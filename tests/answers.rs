@@ -0,0 +1,45 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Checks every day that has a real input against the answers recorded in `src/YYYY/answers.toml`.
+
+use aoc::answers::{Answers, Verdict};
+
+#[test]
+fn test_answers() {
+    let mut failures = Vec::new();
+
+    for day in aoc::days::all() {
+        let answers = Answers::load(day.year).expect("could not read answers.toml");
+        let input = match aoc::read_file(day.year, "inputs", day.day) {
+            Ok(input) => input,
+            Err(_) => continue,
+        };
+
+        for part in 1..=2 {
+            if answers.get(day.day, part).is_none() {
+                continue;
+            }
+
+            let answer = day.solve(part, &input);
+            if let Verdict::Fail { expected } = answers.verify(day.day, part, answer.as_deref()) {
+                failures.push(format!(
+                    "{} day {} part {}: expected {}, got {}",
+                    day.year,
+                    day.day,
+                    part,
+                    expected,
+                    answer.as_deref().unwrap_or("nothing")
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "wrong answers:\n{}",
+        failures.join("\n")
+    );
+}